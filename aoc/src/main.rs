@@ -0,0 +1,47 @@
+mod watch;
+
+use clap::{Parser, Subcommand};
+use std::path::PathBuf;
+
+#[derive(Parser)]
+struct Cli {
+  #[command(subcommand)]
+  command: Command
+}
+
+#[derive(Subcommand)]
+enum Command {
+  /// Rebuild and rerun a day whenever its source or inputs change
+  Watch {
+    /// Which day to watch
+    #[arg(short, long)]
+    day: u32,
+
+    /// Which year the day belongs to
+    #[arg(short, long, default_value_t = 22)]
+    year: u32,
+
+    /// The input file path (default: `inputs/input.txt` of the day)
+    #[arg(short, long)]
+    input: Option<PathBuf>,
+
+    /// How often to poll for changes in milliseconds
+    #[arg(long, default_value_t = 500)]
+    interval: u64
+  }
+}
+
+fn main() {
+  let root = PathBuf::from(env!("CARGO_MANIFEST_DIR")).parent().unwrap().to_path_buf();
+
+  match Cli::parse().command {
+    Command::Watch { day, year, input, interval } => {
+      let day = watch::Day::new(&root, day, year, input);
+      if !day.dir.is_dir() {
+        eprintln!("No crate found for {} at {}", day.name, day.dir.display());
+        std::process::exit(1);
+      }
+      watch::watch(&root, &day, interval);
+    }
+  }
+}
@@ -0,0 +1,202 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::thread::sleep;
+use std::time::{Duration, Instant, SystemTime};
+
+const PARTS: [&str; 2] = ["one", "two"];
+
+/// A day crate and the input it is run on
+pub struct Day { pub name: String, pub dir: PathBuf, pub input: PathBuf }
+
+impl Day {
+  pub fn new(root: &Path, day: u32, year: u32, input: Option<PathBuf>) -> Day {
+    let name  = format!("day{day:02}-{year}");
+    let dir   = root.join(&name);
+    let input = input.unwrap_or_else(|| dir.join("inputs").join("input.txt"));
+    Day { name, dir, input }
+  }
+
+  /// The files and directories whose changes trigger a rerun
+  fn watched(&self) -> Vec<PathBuf> {
+    vec![self.dir.join("src"), self.dir.join("inputs"), self.input.clone()]
+  }
+
+  /// Examples with known answers, read from `inputs/expected.txt` with lines like `simple.txt one 24000`,
+  /// optionally followed by day options like `--row 10`
+  fn examples(&self) -> Vec<Example> {
+    let inputs = self.dir.join("inputs");
+    fs::read_to_string(inputs.join("expected.txt")).unwrap_or_default().lines().filter_map(
+      |line| {
+        match line.split_whitespace().collect::<Vec<&str>>()[..] {
+          [file, part, expected, ref options @ ..] if PARTS.contains(&part) => Some(Example {
+            run     : Run { file: inputs.join(file), part: part.to_string(), options: options.iter().map(|option| option.to_string()).collect() },
+            expected: expected.to_string()
+          }),
+          _                                                                 => None
+        }
+      }
+    ).collect()
+  }
+}
+
+/// A part of the day solved on a file, with the day options
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Run { file: PathBuf, part: String, options: Vec<String> }
+
+impl Run {
+  fn label(&self) -> String {
+    format!("{:<14} {:<3}", self.file.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default(), self.part)
+  }
+}
+
+struct Example { run: Run, expected: String }
+
+#[derive(Debug, Clone, PartialEq)]
+enum Outcome { Answer(String), Failed(String) }
+
+impl Outcome {
+  fn show(&self) -> String {
+    match self {
+      Outcome::Answer(answer) if answer.contains('\n') => answer.lines().fold(String::new(), |acc, line| format!("{acc}\n      {line}")),
+      Outcome::Answer(answer)                          => answer.to_string(),
+      Outcome::Failed(reason)                          => format!("failed ({reason})")
+    }
+  }
+}
+
+type Snapshot = HashMap<PathBuf, SystemTime>;
+
+/// Collect the modification times of all files below the given paths
+fn snapshot(paths: &[PathBuf]) -> Snapshot {
+  fn collect(path: &Path, snapshot: &mut Snapshot) {
+    if path.is_dir() {
+      if let Ok(entries) = fs::read_dir(path) {
+        entries.flatten().for_each(|entry| collect(&entry.path(), snapshot));
+      }
+    } else if let Ok(modified) = fs::metadata(path).and_then(|metadata| metadata.modified()) {
+      snapshot.insert(path.to_path_buf(), modified);
+    }
+  }
+
+  let mut result = Snapshot::new();
+  paths.iter().for_each(|path| collect(path, &mut result));
+  result
+}
+
+/// Extract the answer from the log output of a day, which may span several lines
+fn answer(output: &str) -> Option<String> {
+  let (_, rest) = output.split_once("The result of ")?;
+  let (_, rest) = rest.split_once(" is: ")?;
+  let end = match rest.find("Time elapsed") {
+    Some(i) => rest[..i].rfind('\n').unwrap_or(i),
+    None    => rest.len()
+  };
+  Some(rest[..end].trim_end().to_string())
+}
+
+/// Extract the panic message, or else the last line, from the error output of a day
+fn reason(output: &str) -> String {
+  let lines: Vec<&str> = output.lines().filter(|line| !line.trim().is_empty()).collect();
+  match lines.iter().position(|line| line.contains("panicked at")) {
+    Some(i) if i+1 < lines.len() => lines[i+1].trim().to_string(),
+    _                            => lines.last().map(|line| line.trim().to_string()).unwrap_or_else(|| "no output".to_string())
+  }
+}
+
+fn build(root: &Path, day: &Day) -> Result<(), String> {
+  let output = Command::new("cargo").args(["build", "-q", "-p", &day.name]).current_dir(root).output().map_err(|err| err.to_string())?;
+  if output.status.success() {
+    Ok(())
+  } else {
+    Err(String::from_utf8_lossy(&output.stderr).to_string())
+  }
+}
+
+fn execute(root: &Path, day: &Day, run: &Run) -> Outcome {
+  let output = Command::new("cargo")
+    .args(["run", "-q", "-p", &day.name, "--", "-p", &run.part, "-i"])
+    .arg(&run.file)
+    .args(&run.options)
+    .current_dir(root)
+    .output();
+
+  match output {
+    Ok(output) if output.status.success() => match answer(&String::from_utf8_lossy(&output.stdout)) {
+      Some(answer) => Outcome::Answer(answer),
+      None         => Outcome::Failed("no result printed".to_string())
+    },
+    Ok(output)                            => Outcome::Failed(reason(&String::from_utf8_lossy(&output.stderr))),
+    Err(err)                              => Outcome::Failed(err.to_string())
+  }
+}
+
+/// Rebuild the day, run it on the input and all examples, and compare with the previous outcomes
+fn rerun(root: &Path, day: &Day, round: usize, previous: &mut HashMap<Run, Outcome>) {
+  println!("\n=== Run #{round} of {} ===", day.name);
+
+  let start = Instant::now();
+  if let Err(err) = build(root, day) {
+    println!("Build failed:\n{err}");
+    return;
+  }
+  println!("Built in {} ms", start.elapsed().as_millis());
+
+  if day.input.is_file() {
+    PARTS.iter().for_each(
+      |part| {
+        let run     = Run { file: day.input.clone(), part: part.to_string(), options: vec![] };
+        let outcome = execute(root, day, &run);
+        let change  = match previous.get(&run) {
+          Some(was) if *was == outcome => " (unchanged)".to_string(),
+          Some(was)                    => format!(" (was {})", was.show()),
+          None                         => String::new()
+        };
+        println!("  {} {}{change}", run.label(), outcome.show());
+        previous.insert(run, outcome);
+      }
+    );
+  } else {
+    println!("  No input found at {}", day.input.display());
+  }
+
+  day.examples().into_iter().for_each(
+    |Example { run, expected }| {
+      let outcome = execute(root, day, &run);
+      let passes  = outcome == Outcome::Answer(expected.to_string());
+      let status  = match (passes, previous.get(&run).map(|was| *was == Outcome::Answer(expected.to_string()))) {
+        (true , Some(false)) => "PASS (now passes)".to_string(),
+        (true , _          ) => "PASS".to_string(),
+        (false, Some(true) ) => format!("FAIL (now fails, expected {expected})"),
+        (false, _          ) => format!("FAIL (expected {expected})")
+      };
+      println!("  {} {} {status}", run.label(), outcome.show());
+      previous.insert(run, outcome);
+    }
+  );
+}
+
+/// Poll the source and inputs of the day and rerun it on every change, until interrupted
+pub fn watch(root: &Path, day: &Day, interval: u64) {
+  let interval = Duration::from_millis(interval);
+  let mut previous = HashMap::new();
+  let mut last     = Snapshot::new();
+  let mut round    = 0;
+
+  println!("Watching {} and {} (Ctrl-C to stop)", day.dir.display(), day.input.display());
+
+  loop {
+    let current = snapshot(&day.watched());
+    if current != last {
+      // Wait until the files settle, e.g. while an editor is still writing
+      sleep(interval);
+      if snapshot(&day.watched()) == current {
+        round += 1;
+        rerun(root, day, round, &mut previous);
+        last = current;
+      }
+    }
+    sleep(interval);
+  }
+}
@@ -0,0 +1,77 @@
+//! Benchmarks parsing and both parts of every day on its input, or on its example if it has none.
+//! The input is parsed once up front, so the benchmarks of the parts time only solving them.
+//!
+//! Save a baseline with `cargo bench -p bench -- --save-baseline <name>` and
+//! compare against it later with `cargo bench -p bench -- --baseline <name>`,
+//! which reports every benchmark that regressed or improved.
+use common::args::Part;
+use common::reader::from_file;
+use common::{Input, Solution};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+/// Benchmark parsing and the given parts of a day on `inputs/input.txt`
+fn bench<S: Solution>(c: &mut Criterion, day: &str, parts: &[Part]) {
+  bench_on::<S>(c, day, "input.txt", parts);
+}
+
+/// Benchmark parsing and the given parts of a day on the file in its `inputs`, failing if it is missing
+fn bench_on<S: Solution>(c: &mut Criterion, day: &str, file: &str, parts: &[Part]) {
+  let path  = format!("{}/../{day}/inputs/{file}", env!("CARGO_MANIFEST_DIR"));
+  let lines = from_file(path.clone()).unwrap_or_else(|err| panic!("Cannot benchmark {day} without {path}: {err}"));
+  let mut group = c.benchmark_group(day);
+
+  group.bench_function("parse", |b| b.iter(|| S::prepare(black_box(&lines))));
+  let parsed = S::prepare(&lines);
+  parts.iter().for_each(
+    |part| {
+      let input = Input::new(part.clone(), lines.clone());
+      let f = match part {
+        Part::One => S::one,
+        Part::Two => S::two
+      };
+      group.bench_function(part.to_string().to_lowercase(), |b| b.iter(|| f(black_box(&parsed), black_box(&input))));
+    }
+  );
+
+  group.finish();
+}
+
+fn days(c: &mut Criterion) {
+  let both = [Part::One, Part::Two];
+
+  bench::<day01_21::Day01>(c, "day01-21", &both);
+  bench::<day02_21::Day02>(c, "day02-21", &both);
+  bench::<day01_22::Day01>(c, "day01-22", &both);
+  bench::<day02_22::Day02>(c, "day02-22", &both);
+  bench::<day03_22::Day03>(c, "day03-22", &both);
+  bench::<day04_22::Day04>(c, "day04-22", &both);
+  bench::<day05_22::Day05>(c, "day05-22", &both);
+  bench::<day06_22::Day06>(c, "day06-22", &both);
+  bench::<day07_22::Day07>(c, "day07-22", &both);
+  bench::<day08_22::Day08>(c, "day08-22", &both);
+  bench::<day09_22::Day09>(c, "day09-22", &both);
+  bench::<day10_22::Day10>(c, "day10-22", &both);
+  bench::<day11_22::Day11>(c, "day11-22", &both);
+  bench::<day12_22::Day12>(c, "day12-22", &both);
+  bench::<day13_22::Day13>(c, "day13-22", &both);
+  bench::<day14_22::Day14>(c, "day14-22", &both);
+  bench::<day15_22::Day15>(c, "day15-22", &both);
+  bench::<day16_22::Day16>(c, "day16-22", &both);
+  bench::<day17_22::Day17>(c, "day17-22", &both);
+  bench::<day18_22::Day18>(c, "day18-22", &both);
+  bench::<day19_22::Day19>(c, "day19-22", &both);
+  bench::<day20_22::Day20>(c, "day20-22", &both);
+  bench::<day21_22::Day21>(c, "day21-22", &both);
+  bench::<day22_22::Day22>(c, "day22-22", &both);
+  bench::<day23_22::Day23>(c, "day23-22", &both);
+  // day24-22 has no inputs/input.txt, only the example
+  bench_on::<day24_22::Day24>(c, "day24-22", "simple.txt", &both);
+  bench::<day25_22::Day25>(c, "day25-22", &both);
+}
+
+criterion_group! {
+  name    = benches;
+  config  = Criterion::default().sample_size(10);
+  targets = days
+}
+criterion_main!(benches);
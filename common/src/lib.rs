@@ -6,7 +6,69 @@ pub struct Input {
   /// Which part of the day to solve
   pub part   : args::Part,
   /// The input file as a vector of lines of strings
-  pub lines  : Vec<String>
+  pub lines  : Vec<String>,
+  /// Options only known to the day, e.g. `--explain` or `--agents 3`
+  pub options: Vec<String>
+}
+
+impl Input {
+  /// Create the input for a part from lines, e.g. to solve a day from another crate
+  pub fn new(part: args::Part, lines: Vec<String>) -> Input {
+    Input { verbose: false, part, lines, options: vec![] }
+  }
+
+  /// Add options only known to the day
+  pub fn with_options(mut self, options: &[&str]) -> Input {
+    self.options = options.iter().map(|option| option.to_string()).collect();
+    self
+  }
+
+  /// Whether the day option `--name` was given
+  pub fn flag(&self, name: &str) -> bool {
+    self.options.iter().any(|option| *option == format!("--{name}"))
+  }
+
+  /// The value of the day option given as `--name value` or `--name=value`
+  /// 
+  /// Example:
+  /// ```
+  /// # use common::{Input, args::Part};
+  /// let input = Input::new(Part::One, vec![]).with_options(&["--agents", "2", "--time=26"]);
+  /// assert_eq!(input.option::<usize>("agents"), Some(2));
+  /// assert_eq!(input.option::<usize>("time"), Some(26));
+  /// assert_eq!(input.option::<usize>("rounds"), None);
+  /// ```
+  pub fn option<T>(&self, name: &str) -> Option<T>
+  where T: std::str::FromStr,
+        <T as std::str::FromStr>::Err: std::fmt::Display {
+    let flag  = format!("--{name}");
+    let value = self.options.iter().enumerate().find_map(
+      |(i, option)| {
+        if *option == flag {
+          self.options.get(i+1).cloned()
+        } else {
+          option.strip_prefix(&format!("{flag}=")).map(|value| value.to_string())
+        }
+      }
+    )?;
+    match value.parse::<T>() {
+      Ok(value) => Some(value),
+      Err(err)  => panic!("Invalid value `{value}` for option {flag}: {err}")
+    }
+  }
+}
+
+/// A day at AoC: how to parse its input and how to solve each part from the parsed input
+pub trait Solution {
+  /// The input after parsing, shared by both parts
+  type Parsed;
+
+  /// Parse the lines of the input file
+  fn prepare(lines: &[String]) -> Self::Parsed;
+  /// Solve part one, with the day options of the input
+  fn one(parsed: &Self::Parsed, input: &Input) -> String;
+  /// Solve part two, with the day options of the input
+  fn two(parsed: &Self::Parsed, input: &Input) -> String;
 }
 
 pub mod helper {
@@ -69,7 +131,11 @@ pub mod args {
 
     /// Which logger config file to use
     #[arg(short, long, default_value = "log-config.yml")]
-    pub log: String
+    pub log: String,
+
+    /// Options only known to the day, given after all others, e.g. `--explain`
+    #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+    pub options: Vec<String>
   }
 
   /// Parses the command-line arguments, then populates and returns the Args struct
@@ -128,12 +194,117 @@ pub mod reader {
   }
 }
 
+pub mod memory {
+  use std::alloc::{GlobalAlloc, Layout, System};
+  use std::fmt;
+  use std::sync::atomic::{AtomicUsize, Ordering};
+
+  /// Heap usage since the last call to `reset`
+  #[derive(Debug, Clone, Copy, PartialEq)]
+  pub struct Usage {
+    /// The most bytes allocated at the same time, on top of what was allocated at the reset
+    pub peak_bytes : usize,
+    /// How many allocations (including reallocations) were made
+    pub allocations: usize
+  }
+
+  impl fmt::Display for Usage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+      write!(f, "{} bytes peak, {} allocations", self.peak_bytes, self.allocations)
+    }
+  }
+
+  static CURRENT    : AtomicUsize = AtomicUsize::new(0);
+  static PEAK       : AtomicUsize = AtomicUsize::new(0);
+  static BASELINE   : AtomicUsize = AtomicUsize::new(0);
+  static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+  /// Forwards to the system allocator and counts the bytes and allocations passing through it
+  pub struct CountingAllocator;
+
+  impl CountingAllocator {
+    fn allocated(size: usize) {
+      let current = CURRENT.fetch_add(size, Ordering::Relaxed) + size;
+      PEAK.fetch_max(current, Ordering::Relaxed);
+      ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn deallocated(size: usize) {
+      CURRENT.fetch_sub(size, Ordering::Relaxed);
+    }
+  }
+
+  unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+      let ptr = System.alloc(layout);
+      if !ptr.is_null() { CountingAllocator::allocated(layout.size()); }
+      ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+      let ptr = System.alloc_zeroed(layout);
+      if !ptr.is_null() { CountingAllocator::allocated(layout.size()); }
+      ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+      System.dealloc(ptr, layout);
+      CountingAllocator::deallocated(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+      let new_ptr = System.realloc(ptr, layout, new_size);
+      if !new_ptr.is_null() {
+        CountingAllocator::deallocated(layout.size());
+        CountingAllocator::allocated(new_size);
+      }
+      new_ptr
+    }
+  }
+
+  /// Every binary linking `common` with the `memory` feature counts its heap usage, e.g.:
+  /// `cargo run -p day14-22 --features common/memory -- -i day14-22/inputs/input.txt`
+  #[cfg(feature = "memory")]
+  #[global_allocator]
+  static GLOBAL: CountingAllocator = CountingAllocator;
+
+  /// Start measuring from the current heap usage
+  pub fn reset() {
+    let current = CURRENT.load(Ordering::Relaxed);
+    BASELINE.store(current, Ordering::Relaxed);
+    PEAK.store(current, Ordering::Relaxed);
+    ALLOCATIONS.store(0, Ordering::Relaxed);
+  }
+
+  /// The heap usage since the last reset, if the `memory` feature is enabled
+  pub fn usage() -> Option<Usage> {
+    if !cfg!(feature = "memory") { return None; }
+    Some(Usage {
+      peak_bytes : PEAK.load(Ordering::Relaxed).saturating_sub(BASELINE.load(Ordering::Relaxed)),
+      allocations: ALLOCATIONS.load(Ordering::Relaxed)
+    })
+  }
+
+  #[cfg(test)]
+  mod tests {
+      use super::*;
+
+      #[test]
+      fn display_usage() {
+          assert_eq!(Usage { peak_bytes: 1024, allocations: 3 }.to_string(), "1024 bytes peak, 3 allocations");
+      }
+  }
+}
+
 pub mod init {
   use super::args::{Args,Part};
   use super::logger::initialize;
   use super::reader::from_file;
+  use super::memory;
+  use super::Solution;
   use log::{trace,info};  
   use std::fmt::Display;
+  use std::time::Instant;
 
   /// Parse command-line arguments, 
   /// initialize log4rs, and 
@@ -148,6 +319,9 @@ pub mod init {
     trace!("Loaded log config from {}", args.log);
     trace!("Start logging");
     trace!("Parsing input from `{}`", &args.input);
+    if !args.options.is_empty() {
+      trace!("Day options: {}", args.options.join(" "));
+    }
 
     let lines_result: Result<Vec<String>,std::io::Error> = from_file(args.input);
     if lines_result.is_err() {
@@ -157,7 +331,7 @@ pub mod init {
     let mut lines: Vec<String> = Vec::new();
     lines_result.unwrap().into_iter().for_each(|l| lines.push(l));
 
-    super::Input { verbose: args.verbose, part: args.part, lines: lines }
+    super::Input { verbose: args.verbose, part: args.part, lines, options: args.options }
   }
 
   /// Print the result of the part of a day
@@ -166,8 +340,41 @@ pub mod init {
     info!("The result of {} (Part {}) is: {}", day, part, result);
   }
 
+  /// Print the heap usage of the part of a day, if it was measured
+  pub fn print_memory() {
+    if let Some(usage) = memory::usage() {
+      info!("Memory used: {}", usage);
+    }
+  }
+
   /// Shut down operations
   pub fn shutdown() {
     trace!("Shutting down");
   }
+
+  /// Start up, 
+  /// parse the input and solve the part of the day given on the command-line, 
+  /// print the result with the time elapsed and memory used, and 
+  /// shut down
+  pub fn run<S: Solution>(day: &str) {
+    let input = startup();
+
+    if input.verbose {
+      trace!("Running {} (Part {}) with input:\n{}", day, input.part, input.lines.join("\n"));
+    }
+
+    let f = match input.part {
+      Part::One => S::one,
+      Part::Two => S::two
+    };
+
+    memory::reset();
+    let start = Instant::now();
+    let parsed = S::prepare(&input.lines);
+    print(day.to_string(), input.part.clone(), f(&parsed, &input));
+    info!("Time elapsed: {} ms", start.elapsed().as_millis());
+    print_memory();
+
+    shutdown();
+  }
 }
\ No newline at end of file
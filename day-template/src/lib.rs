@@ -0,0 +1,19 @@
+use common::{Input, Solution};
+
+pub fn one(_lines: &Vec<String>, _input: &Input) -> String {
+  return "42".to_string();
+}
+
+pub fn two(_lines: &Vec<String>, _input: &Input) -> String {
+  return "42".to_string();
+}
+
+pub struct Template;
+
+impl Solution for Template {
+  type Parsed = Vec<String>;
+
+  fn prepare(lines: &[String]) -> Vec<String> { lines.to_vec() }
+  fn one(lines: &Vec<String>, input: &Input) -> String { one(lines, input) }
+  fn two(lines: &Vec<String>, input: &Input) -> String { two(lines, input) }
+}
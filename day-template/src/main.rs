@@ -1,33 +1,6 @@
-use common::Input;
-use common::args::Part;
-use common::init::{startup, print, shutdown};
-use log::{trace,info};
-use std::time::Instant;
-
-fn one(_input: &Input) -> String {
-  return "42".to_string();
-}
-
-fn two(_input: &Input) -> String {
-  return "42".to_string();
-}
+use common::init::run;
+use day_template::Template;
 
 fn main() {
-  let day  : String = env!("CARGO_PKG_NAME").to_string();
-  let input: Input = startup();
-
-  if input.verbose {
-    trace!("Running {} (Part {}) with input:\n{}", env!("CARGO_PKG_NAME"), input.part, input.lines.join("\n"));
-  }
-
-  let f = match input.part {
-    Part::One => one,
-    Part::Two => two
-  };
-
-  let start = Instant::now();
-  print(day, input.part.clone(), f(&input));
-  info!("Time elapsed: {} ms", start.elapsed().as_millis());
-
-  shutdown();
+  run::<Template>(env!("CARGO_PKG_NAME"));
 }
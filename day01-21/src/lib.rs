@@ -0,0 +1,57 @@
+use common::helper::{from_strings};
+use common::{Input, Solution};
+
+use std::collections::VecDeque;
+
+pub fn one(depths: &Vec<i32>, _input: &Input) -> String {  
+  struct Acc {
+    prev : Option<i32>,
+    count: i32
+  }
+
+  let acc = depths.iter().copied().fold(Acc { prev: None, count: 0 }, |acc, curr| {
+    match acc {
+      Acc { prev: None,       count } => Acc { prev: Some(curr), count: count },
+      Acc { prev: Some(prev), count } => Acc { prev: Some(curr), count: count + if prev < curr {1} else {0} }
+    }
+  });
+
+  acc.count.to_string()
+}
+
+pub fn two(depths: &Vec<i32>, _input: &Input) -> String {
+  struct Acc {
+    prevs: VecDeque<i32>,
+    count: i32
+  }
+  let sum = |acc: i32, x: &i32| acc+x;
+
+  let acc = depths.iter().copied().fold(Acc { prevs: VecDeque::new(), count: 0 }, |acc, curr| {
+    match acc {
+      Acc { prevs, count: _ } if prevs.len() > 3 => panic!("On day two: prevs cannot have more than three elements. prevs: {:?}", prevs),
+      Acc { mut prevs, count } if prevs.len() < 3 => {
+        Acc { prevs: { prevs.push_front(curr); prevs }, count: count }
+      },
+      Acc { mut prevs, count } => {
+        let prev_sum = prevs.iter().fold(0, sum);
+        prevs.pop_back();
+        prevs.push_front(curr);
+        let curr_sum = prevs.iter().fold(0, sum);
+
+        Acc { prevs: prevs, count: count + if prev_sum < curr_sum {1} else {0} }
+      }
+    }
+  });
+
+  acc.count.to_string()
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+  type Parsed = Vec<i32>;
+
+  fn prepare(lines: &[String]) -> Vec<i32> { from_strings(lines.to_vec()) }
+  fn one(parsed: &Vec<i32>, input: &Input) -> String { one(parsed, input) }
+  fn two(parsed: &Vec<i32>, input: &Input) -> String { two(parsed, input) }
+}
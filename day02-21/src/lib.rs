@@ -0,0 +1,118 @@
+use common::{Input, Solution};
+use std::error::Error;
+use std::fmt;
+
+#[derive(Debug)]
+pub struct Inst { pub dir: Dir, pub arg: i32 }
+
+#[derive(Debug)]
+pub enum Dir { Forward, Up, Down }
+
+pub fn lines_to_instructions(lines: &[String]) -> Vec<Inst> {
+
+  #[derive(Debug)]
+  enum Reason { MalformedLine, InvalidDirection, MalformedInteger }
+
+  impl fmt::Display for Reason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+      write!(f, "{}", match &self {
+        Reason::MalformedLine    => "malformed line",
+        Reason::InvalidDirection => "invalid direction",
+        Reason::MalformedInteger => "malformed integer"
+      })
+    }
+  }
+
+  impl Error for Reason {}
+
+  #[derive(Debug)]
+  struct ParseError { reason: Reason, input: String }
+
+  impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "failed to parse {}: {}", &self.input, &self.reason)
+    }
+  }
+
+  impl Error for ParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+      Some(&self.reason)
+    }
+  }
+
+  fn to_dir(s: &str) -> Result<Dir,ParseError> {
+    match s.to_lowercase().as_str() {
+      "forward" => Ok(Dir::Forward),
+      "up"      => Ok(Dir::Up),
+      "down"    => Ok(Dir::Down),
+      _         => Err(ParseError { reason: Reason::InvalidDirection, input: s.to_string() })
+    }
+  }
+
+  let instructions_results: Vec<Result<Inst,ParseError>> = lines.iter().map(
+    |l| {
+      let splits: Vec<&str> = l.split(" ").collect();
+      match splits[..] {
+        [dir_string,arg_string] => match to_dir(dir_string) {
+                                     Ok(dir)    => 
+                                        match arg_string.parse::<i32>() {
+                                          Ok(arg) => Ok(Inst{dir: dir, arg: arg}),
+                                          _       => Err(ParseError { input: l.to_string(), reason: Reason::MalformedInteger })
+                                        },
+                                     Err(error) => Err(error) 
+                                   },
+        _                       => Err(ParseError { input: l.to_string(), reason: Reason::MalformedLine })
+      }
+    }
+  ).collect();
+
+  if let Some(Err(err)) = instructions_results.iter().find(|inst| inst.is_err()) {
+    panic!("Lines to Inst failed: {err}");
+  }
+
+  instructions_results.into_iter().map(|inst| inst.unwrap()).collect()
+}
+
+pub fn one(instructions: &Vec<Inst>, _input: &Input) -> String {
+  struct State { x: i32, y: i32 }
+
+  let result = instructions.iter().fold(State {x: 0, y:0 }, 
+    | mut state, Inst { dir, arg } | {
+      match dir {
+        Dir::Forward => state.x += arg,
+        Dir::Up      => state.y -= arg,
+        Dir::Down    => state.y += arg
+      };
+      state
+    }
+  );
+
+  (result.x * result.y).to_string()
+}
+
+pub fn two(instructions: &Vec<Inst>, _input: &Input) -> String {
+  struct State { aim: i32, x: i32, y: i32 }
+
+  let result = instructions.iter().fold(State {aim: 0, x: 0, y:0 }, 
+    | mut state, Inst { dir, arg } | {
+      match dir {
+        Dir::Forward => { state.x += arg; state.y += arg*state.aim },
+        Dir::Up      => state.aim -= arg,
+        Dir::Down    => state.aim += arg
+      };
+      state
+    }
+  );
+
+  (result.x * result.y).to_string()
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+  type Parsed = Vec<Inst>;
+
+  fn prepare(lines: &[String]) -> Vec<Inst> { lines_to_instructions(lines) }
+  fn one(parsed: &Vec<Inst>, input: &Input) -> String { one(parsed, input) }
+  fn two(parsed: &Vec<Inst>, input: &Input) -> String { two(parsed, input) }
+}
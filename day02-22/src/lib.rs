@@ -0,0 +1,148 @@
+use common::{Input, Solution};
+use std::error::Error;
+use std::fmt;
+
+#[derive(Debug)]
+pub enum Reason { MalformedLine, NotAnAction }
+
+impl fmt::Display for Reason {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}", match &self {
+      Reason::MalformedLine => "malformed line",
+      Reason::NotAnAction   => "not an action"
+    })
+  }
+}
+
+impl Error for Reason {}
+
+#[derive(Debug)]
+pub struct ParseError { pub reason: Reason, pub input: String }
+
+impl fmt::Display for ParseError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+      write!(f, "failed to parse {}: {}", &self.input, &self.reason)
+  }
+}
+
+impl Error for ParseError {
+  fn source(&self) -> Option<&(dyn Error + 'static)> {
+    Some(&self.reason)
+  }
+}
+
+#[derive(Debug,PartialEq,Clone,Copy)]
+pub enum Action { Rock, Paper, Scissors }
+
+#[derive(Debug)]
+pub struct Round { pub player: Action, pub opponent: Action }
+
+impl Round {
+/// Converts a character c into an action
+/// Returns an NotAnAction error, if the character could not be parsed
+  pub fn from_char(c: char) -> Result<Action,ParseError> {
+    match c {
+      'A'|'X' => Ok(Action::Rock),
+      'B'|'Y' => Ok(Action::Paper),
+      'C'|'Z' => Ok(Action::Scissors),
+      _       => Err(ParseError { reason: Reason::NotAnAction, input: c.to_string()})
+    }
+  }
+
+  pub fn from_decision_char(c: char, opponent: Action) -> Result<Action,ParseError> {
+    match (c, opponent) {
+      ('X', Action::Paper)
+    | ('Y', Action::Rock)   
+    | ('Z', Action::Scissors) => Ok(Action::Rock),
+      ('X', Action::Scissors)
+    | ('Y', Action::Paper)   
+    | ('Z', Action::Rock)     => Ok(Action::Paper),
+      ('X', Action::Rock)
+    | ('Y', Action::Scissors)   
+    | ('Z', Action::Paper)    => Ok(Action::Scissors),
+      _                       => Err(ParseError { reason: Reason::NotAnAction, input: c.to_string()})
+    }
+  }
+
+  /// The round with the action of the player read as the outcome the player decides on instead
+  pub fn decided(&self) -> Round {
+    let c = match self.player { Action::Rock => 'X', Action::Paper => 'Y', Action::Scissors => 'Z' };
+    Round { player: Round::from_decision_char(c, self.opponent).unwrap(), opponent: self.opponent }
+  }
+}
+
+pub fn prepare(lines: &[String], to_round: fn(String) -> Result<Round,ParseError>) -> Vec<Round> {
+  let round_lines = lines.to_vec();
+
+  let round_results: Vec<Result<Round,ParseError>> = round_lines.into_iter().map(to_round).collect();
+
+  if let Some(Err(err)) = round_results.iter().find(|l| l.is_err()) {
+    panic!("{err}");
+  }
+
+  round_results.into_iter().map(|round| round.unwrap()).collect()
+}
+
+pub fn score(gameplan: &Vec<Round>) -> i32 {
+  gameplan.iter().fold(0, |acc, &Round {player, opponent}| 
+    acc 
+      +
+    match (player, opponent) {
+      (Action::Rock    , Action::Scissors)
+    | (Action::Scissors, Action::Paper) 
+    | (Action::Paper   , Action::Rock)     => 6,
+      _              if player == opponent => 3,
+      _                                    => 0
+    } 
+      +
+    match player {
+      Action::Rock     => 1, 
+      Action::Paper    => 2,
+      Action::Scissors => 3
+    } 
+  )
+}
+
+/// Reads a line as the actions of the opponent and the player
+pub fn to_round(line: String) -> Result<Round,ParseError> {
+  match line.chars().collect::<Vec<char>>()[..] {
+    [opponent_char,' ',player_char] => match Round::from_char(player_char) {
+      Ok(player_action) => match Round::from_char(opponent_char) {
+        Ok(opponent_action) => Ok(Round { player  : player_action, 
+                                          opponent: opponent_action }),
+        Err(err)            => Err(err)},
+      Err(err)          => Err(err)},
+    _                               => Err(ParseError { reason: Reason::MalformedLine, input: line })
+  }
+}
+
+/// Reads a line as the action of the opponent and the outcome the player decides on
+pub fn to_decided_round(line: String) -> Result<Round,ParseError> {
+  match line.chars().collect::<Vec<char>>()[..] {
+    [opponent_char,' ',player_char] => match Round::from_char(opponent_char) {
+      Ok(opponent_action) => match Round::from_decision_char(player_char, opponent_action) {
+        Ok(player_action)   => Ok(Round { player  : player_action, 
+                                          opponent: opponent_action }),
+        Err(err)            => Err(err)},
+      Err(err)            => Err(err)},
+    _                               => Err(ParseError { reason: Reason::MalformedLine, input: line })
+  }
+}
+
+pub fn one(rounds: &Vec<Round>, _input: &Input) -> String {
+  score(rounds).to_string()
+}
+
+pub fn two(rounds: &Vec<Round>, _input: &Input) -> String {
+  score(&rounds.iter().map(Round::decided).collect()).to_string()
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+  type Parsed = Vec<Round>;
+
+  fn prepare(lines: &[String]) -> Vec<Round> { prepare(lines, to_round) }
+  fn one(parsed: &Vec<Round>, input: &Input) -> String { one(parsed, input) }
+  fn two(parsed: &Vec<Round>, input: &Input) -> String { two(parsed, input) }
+}
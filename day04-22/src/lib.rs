@@ -0,0 +1,67 @@
+use common::{Input, Solution};
+use std::fmt;
+use common::helper::{from_strings};
+
+pub struct Range {
+  pub from: i32,
+  pub to  : i32
+}
+
+impl fmt::Display for Range {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f,"{}-{}",&self.from, &self.to)
+  }
+}
+
+pub struct Assignment {
+  pub ranges: Vec<Range>
+}
+
+impl fmt::Display for Assignment {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{}{}", &self.ranges[0], &self.ranges[1..].iter().fold("".to_string(), |acc,range| format!("{acc},{range}")))
+  }
+}
+
+pub fn prepare(lines: &[String]) -> Vec<Assignment> {
+  lines.iter().map(
+    |line|
+      Assignment { 
+        ranges: line.split(',').map(
+                  |assignment_string| {
+                    let bounds: Vec<i32> = from_strings(assignment_string.split('-').map(|str| str.to_string()).collect());
+                    Range {from: bounds[0], to: bounds[1]}
+                  }
+                ).collect()
+      }
+  ).collect()
+}
+
+pub fn one(assignments: &Vec<Assignment>, _input: &Input) -> String {
+  assignments.iter().fold(
+    0,
+    |acc, assignment| acc 
+                       + 
+                      ((assignment.ranges[0].from <= assignment.ranges[1].from && assignment.ranges[0].to >= assignment.ranges[1].to ||
+                        assignment.ranges[1].from <= assignment.ranges[0].from && assignment.ranges[1].to >= assignment.ranges[0].to) as i32) 
+  ).to_string()
+}
+
+pub fn two(assignments: &Vec<Assignment>, _input: &Input) -> String {
+  (assignments.len() as i32 - assignments.iter().fold(
+    0,
+    |acc, assignment| acc 
+                       + 
+                      ((assignment.ranges[0].to < assignment.ranges[1].from || assignment.ranges[0].from > assignment.ranges[1].to) as i32) 
+  )).to_string()
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+  type Parsed = Vec<Assignment>;
+
+  fn prepare(lines: &[String]) -> Vec<Assignment> { prepare(lines) }
+  fn one(parsed: &Vec<Assignment>, input: &Input) -> String { one(parsed, input) }
+  fn two(parsed: &Vec<Assignment>, input: &Input) -> String { two(parsed, input) }
+}
@@ -0,0 +1,145 @@
+use common::{Input, Solution};
+use std::fmt;
+use std::cmp::{min,max};
+
+#[derive(Debug, Clone)]
+pub struct Crate { pub label: char } 
+
+impl fmt::Display for Crate {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "[{}]", &self.label)
+  }
+}
+
+pub type Stack = Vec<Crate>;
+
+#[derive(Debug, Clone)]
+pub struct Cargo { pub stacks: Vec<Stack> }
+
+impl Cargo {
+  pub fn height(&self) -> usize {
+    (&self).stacks.iter().fold(0,|acc,stack| max(acc,stack.len()))
+  }
+}
+
+impl fmt::Display for Cargo {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    let mut result = "".to_string();
+    for i in (0..(&self).height()).rev() {
+      result = format!("{result}\n{}",&self.stacks.iter().fold("".to_string(), |acc, stack| format!("{acc} {}",if let Some(c) = stack.get(i) {c.to_string()} else {"   ".to_string()})));
+    }
+    let mut i = 0;
+    write!(f,"{result}\n{}", &self.stacks.iter().fold(" ".to_string(),|acc,_| { i += 1; format!("{acc} {i}  ") }))
+  }
+}
+
+#[derive(Debug)]
+pub struct Move { pub count: i32, pub from: usize, pub to: usize }
+
+impl fmt::Display for Move {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f,"move {} from {} to {}", &self.count, &self.from, &self.to)
+  }
+}
+
+#[derive(Debug)]
+pub struct Harbor { pub cargo: Cargo, pub moves: Vec<Move> }
+
+impl fmt::Display for Harbor {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{}\n{}\n", &self.cargo.to_string(), &self.moves.iter().fold("".to_string(),|acc,mv| format!("{acc}\n{mv}")))
+  }
+}
+
+pub fn prepare(lines: &[String]) -> Harbor {
+  let (cargo_lines, move_lines_option) = lines.iter().fold(
+    (vec![], None::<Vec<String>>), 
+    |(mut cargo,moves_option), line| 
+      match moves_option {
+        Some(mut moves) => {
+          moves.push(line.to_string());
+          (cargo, Some(moves))
+        },
+        None            => 
+          match line.len() {
+            0 => (cargo, Some(vec![])),
+            _ => { 
+              cargo.push(line.to_string()); 
+              (cargo, moves_option) 
+            }
+          }
+      }
+  );
+
+  let mut stacks: Vec<Stack> = (0..cargo_lines.last().unwrap().split_whitespace().last().unwrap().parse::<i32>().unwrap())
+    .fold(vec![], |mut acc,_| { acc.push(vec![]); acc });
+
+  (0..cargo_lines.len()-1).rev().fold(
+    (), 
+    |_, i| {
+      let mut line = cargo_lines[i].to_string();
+      for j in 0..stacks.len() {
+
+        let rest = line.split_off(min(3,line.len()));
+        let curr = line;
+        line = rest;
+        match curr.chars().nth(1) {
+          Some(' ') | None => {}, // Trailing whitespace may have been trimmed
+          Some( c )        => stacks[j].push(Crate { label: c })
+        }
+        if line.len() > 0 { line.remove(0); }
+      }
+    }
+  );
+
+  let move_lines = if let Some(move_lines) = move_lines_option {move_lines} else {vec![]};
+  let moves: Vec<Move> = move_lines.iter().map(
+    |line| {
+      let tokens: Vec<&str> = line.split_whitespace().collect();
+      Move { 
+        count: tokens[1].parse::<i32>().unwrap(), 
+        from : tokens[3].parse::<usize>().unwrap(), 
+        to   : tokens[5].parse::<usize>().unwrap()}
+    }
+  ).collect();  
+
+  Harbor { cargo: Cargo { stacks: stacks }, moves: moves }  
+}
+
+pub fn one(harbor: &Harbor, _input: &Input) -> String {
+  let mut cargo = harbor.cargo.clone();
+
+  for Move { count, from, to } in harbor.moves.iter() {
+    for _ in 0..*count {
+      let c = cargo.stacks[*from-1].pop().unwrap();
+      cargo.stacks[*to-1].push(c);
+    }
+  }
+
+  cargo.stacks.iter().fold("".to_string(), |acc, stack| format!("{acc}{}", stack.last().unwrap().label))
+}
+
+pub fn two(harbor: &Harbor, _input: &Input) -> String {
+  let mut cargo = harbor.cargo.clone();
+  
+  for Move { count, from, to } in harbor.moves.iter() {
+    let mut crates = vec![];
+    for _ in 0..*count {
+      crates.push(cargo.stacks[*from-1].pop().unwrap());
+    }
+    crates = crates.into_iter().rev().collect();
+    cargo.stacks[*to-1].append(&mut crates);
+  }
+
+  cargo.stacks.iter().fold("".to_string(), |acc, stack| format!("{acc}{}", stack.last().unwrap().label))
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+  type Parsed = Harbor;
+
+  fn prepare(lines: &[String]) -> Harbor { prepare(lines) }
+  fn one(parsed: &Harbor, input: &Input) -> String { one(parsed, input) }
+  fn two(parsed: &Harbor, input: &Input) -> String { two(parsed, input) }
+}
@@ -0,0 +1,46 @@
+use day05_22::prepare;
+use proptest::prelude::*;
+
+/// Stacks of crates from bottom to top, at most nine as the labels are single digits
+fn stacks() -> impl Strategy<Value = Vec<Vec<char>>> {
+  prop::collection::vec(prop::collection::vec(prop::char::range('A', 'Z'), 0..8), 1..=9)
+}
+
+fn moves() -> impl Strategy<Value = Vec<(i32, usize, usize)>> {
+  prop::collection::vec((1..50i32, 1..=9usize, 1..=9usize), 0..10)
+}
+
+/// Draw the crate diagram in fixed-width columns like the puzzle input, optionally trimming trailing whitespace
+fn diagram(stacks: &[Vec<char>], trim: bool) -> Vec<String> {
+  let height = stacks.iter().map(|stack| stack.len()).max().unwrap_or(0);
+  let mut lines: Vec<String> = (0..height).rev().map(
+    |level| {
+      stacks.iter().map(
+        |stack| match stack.get(level) {
+          Some(label) => format!("[{label}]"),
+          None        => "   ".to_string()
+        }
+      ).collect::<Vec<String>>().join(" ")
+    }
+  ).collect();
+  lines.push((1..=stacks.len()).map(|i| format!(" {i} ")).collect::<Vec<String>>().join(" "));
+  if trim {
+    lines = lines.into_iter().map(|line| line.trim_end().to_string()).collect();
+  }
+  lines
+}
+
+proptest! {
+  #[test]
+  fn parse_diagram_and_moves(stacks in stacks(), moves in moves(), trim in any::<bool>()) {
+    let mut lines = diagram(&stacks, trim);
+    lines.push(String::new());
+    moves.iter().for_each(|(count, from, to)| lines.push(format!("move {count} from {from} to {to}")));
+
+    let harbor = prepare(&lines);
+    let parsed_stacks: Vec<Vec<char>> = harbor.cargo.stacks.iter().map(|stack| stack.iter().map(|c| c.label).collect()).collect();
+    let parsed_moves: Vec<(i32, usize, usize)> = harbor.moves.iter().map(|mv| (mv.count, mv.from, mv.to)).collect();
+    prop_assert_eq!(parsed_stacks, stacks);
+    prop_assert_eq!(parsed_moves, moves);
+  }
+}
@@ -0,0 +1,42 @@
+use common::{Input, Solution};
+
+pub fn start(lines: &String, length: usize) -> i32 {
+  let (_, pos) = lines.chars().enumerate().fold(
+    (vec![], None),
+    | (mut seq, mut pos), (i,c)| {
+      //trace!("{seq:?},{pos:?}");
+      match pos {
+        None    => {
+          if let Some(j) = seq.iter().position(|&_c| _c == c) {
+            seq.push(c);
+            seq = seq.split_off(j+1);
+          } else {
+            seq.push(c);
+            pos = if seq.len() == length {Some(i+1)} else {pos};
+          }
+        },
+        Some(_) => {}
+      }
+      (seq, pos)
+    }
+  );
+  return pos.unwrap() as i32;
+}
+
+pub fn one(signal: &String, _input: &Input) -> String {
+  return start(signal, 4).to_string();
+}
+
+pub fn two(signal: &String, _input: &Input) -> String {
+  return start(signal, 14).to_string();
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+  type Parsed = String;
+
+  fn prepare(lines: &[String]) -> String { lines[0].clone() }
+  fn one(parsed: &String, input: &Input) -> String { one(parsed, input) }
+  fn two(parsed: &String, input: &Input) -> String { two(parsed, input) }
+}
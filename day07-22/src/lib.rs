@@ -0,0 +1,232 @@
+use common::{Input, Solution};
+use log::trace;
+use std::fmt;
+use core::slice::Iter;
+
+#[derive(Debug)]
+pub struct File { pub name: String, pub size: i64 }
+
+impl fmt::Display for File {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "- {} (file, size={})", &self.name, &self.size)
+  }
+}
+
+#[derive(Debug)]
+pub struct Directory { pub name: String, pub subdirs: Vec<Directory>, pub files: Vec<File> }
+
+impl Directory {
+  pub fn new(name: String) -> Directory {
+    Directory { name: name, subdirs: vec![], files: vec![] }
+  }
+}
+
+impl fmt::Display for Directory {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f,"- {} (dir){}{}", &self.name, &self.subdirs.iter().fold(
+      String::new(),
+      |acc, subdir| {
+        format!("{acc}{}", subdir.to_string().lines().fold(String::new(), |acc, line| format!("{acc}\n  {line}")))
+      }
+    ), &self.files.iter().fold(
+      "\n".to_string(),
+      |acc, file| {
+        format!("{acc}  {file}\n")
+      }
+    ))
+  }
+}
+
+#[derive(Debug)]
+pub struct Size { pub name: String, pub value: i64, pub subsizes: Vec<Size>}
+
+impl Size {
+  pub fn new(name: String) -> Size {
+    return Size { name: name, value: 0, subsizes: vec![] }
+  }
+}
+
+#[derive(Debug)]
+pub enum Instruction {
+  CD(String),
+  LS,
+  Dir(String),
+  File(String,i64)
+}
+
+pub fn prepare(lines: &[String]) -> Vec<Instruction> {
+  lines.iter().map(
+    |line| {
+      match line.chars().next().unwrap() {
+        '$'                                     => {
+          match line.split_whitespace().collect::<Vec<&str>>()[..] {
+            [_,_,dir] => Instruction::CD(dir.to_string()),
+            [_,_]           => Instruction::LS,
+             _              => panic!("Could not parse command. Got: {line}")
+          }
+        }, 
+        '0'|'1'|'2'|'3'|'4'|'5'|'6'|'7'|'8'|'9' => {
+          match line.split_whitespace().collect::<Vec<&str>>()[..] {
+            [size,name] => Instruction::File(name.to_string(), size.parse::<i64>().unwrap()),
+             _                      => panic!("Could not parse file. Got: {line}")
+          }
+        },
+         _                                      => {
+          match line.split_whitespace().collect::<Vec<&str>>()[..] {
+            [_,name] => Instruction::Dir(name.to_string()), 
+             _             => panic!("Could not parse directory. Got: {line}")
+          }
+         }  
+      }
+    }
+  ).collect()
+}
+
+pub fn to_filesystem(instructions: &[Instruction]) -> (Directory, i64) {
+  let first_inst = &instructions[0];
+  let root: Directory = 
+    match first_inst {
+      Instruction::CD(name)     => match name.as_str() {
+        ".." => panic!("Tried to change dir into root. Got: {first_inst:?}"),
+        name => { 
+          Directory::new(name.to_string())
+        }
+      },
+      _                         => panic!("Expected `cd` instruction. Got: {first_inst:?}")
+    };
+  let mut total_size = 0;
+  let (ancestors, current): (Vec<Directory>, Directory) = instructions[1..].iter().fold(
+       (vec![], root),
+      |(mut ancestors, mut current), inst| {
+      match inst {
+        Instruction::CD(name)        => {
+          match name.as_str() {
+            ".."    => {
+              match ancestors.pop() {
+                Some(mut parent) => {
+                  parent.subdirs.push(current);
+                  (ancestors, parent)
+                },
+                None         => panic!("Tried to move up one level at root")
+              }
+            },
+            name    => {
+              let child = if let Some(i) = current.subdirs.iter().position(|d| d.name == name) {
+                current.subdirs.remove(i)
+              } else {
+                Directory::new(name.to_string())
+              };
+              ancestors.push(current);
+              (ancestors, child)
+            }
+          }
+        },
+        Instruction::LS               => (ancestors, current),
+        Instruction::Dir(name)        => {
+          if current.subdirs.iter().find(|d| d.name == *name).is_none() {
+            current.subdirs.push(Directory::new(name.to_string()));
+          }
+          (ancestors, current)
+        },
+        Instruction::File(name, size) =>  {
+          if current.files.iter().find(|f| f.name == *name).is_none() {
+            total_size += *size;
+            current.files.push(File { name: name.to_string(), size: *size });
+          }
+          (ancestors, current)
+        }
+      }
+    }
+  );
+
+  (ancestors.into_iter().rev().fold(current, |current, mut parent| {parent.subdirs.push(current); parent }), total_size)
+}
+
+pub fn one(instructions: &Vec<Instruction>, _input: &Input) -> String {
+  let (filesystem, _) = to_filesystem(instructions);
+
+  trace!("\n{filesystem}");
+
+  let mut rest: Vec<(Size, &Directory, Iter<Directory>)> = vec![(Size::new(filesystem.name.to_string()), &filesystem, filesystem.subdirs.iter())];
+  let mut sizes_found: Vec<i64> = vec![];
+
+  while !rest.is_empty() {
+    let (mut size, curr, mut children) = rest.pop().unwrap();
+    match children.next() {
+      Some(child) => {
+        rest.push((size,curr,children));
+        rest.push((Size::new(child.name.to_string()), child, child.subdirs.iter()));
+      },
+      None        => {
+        size.value += size.value + 
+                      size.subsizes.iter().fold(0, |acc,s| acc+s.value) +
+                         curr.files.iter().fold(0, |acc,f| acc+f.size);
+        if size.value <= 100000 { sizes_found.push(size.value) }
+        if let Some((parent_size, _, _)) = rest.last_mut() {
+          parent_size.subsizes.push(size);
+        }
+      }
+    }
+  }
+
+  trace!("\n{sizes_found:?}");
+
+  return sizes_found.iter().sum::<i64>().to_string();
+}
+
+pub fn two(instructions: &Vec<Instruction>, _input: &Input) -> String {
+  let total_size               = 70000000;
+  let needed_size              = 30000000;
+  let (filesystem, used_space) = to_filesystem(instructions);
+  let to_be_deleted            = needed_size - (total_size  - used_space);
+
+  let mut rest: Vec<(Size, &Directory, Iter<Directory>)> = vec![(Size::new(filesystem.name.to_string()), &filesystem, filesystem.subdirs.iter())];
+  let mut delete_this_option: Option<(String, i64)> = None;
+
+  while !rest.is_empty() {
+    let (mut size, curr, mut children) = rest.pop().unwrap();
+    match children.next() {
+      Some(child) => {
+        rest.push((size,curr,children));
+        rest.push(
+          (Size::new(format!("{}/{}", rest.iter().fold(
+            "".to_string(), 
+            |acc, (_,dir,_)| 
+              if dir.name != "/".to_string() {format!("{acc}/{}", dir.name)} else {acc}
+            ), child.name)
+          ), child, child.subdirs.iter())
+        );
+      },
+      None        => {
+        size.value += size.value + 
+                      size.subsizes.iter().fold(0, |acc,s| acc+s.value) +
+                         curr.files.iter().fold(0, |acc,f| acc+f.size);
+        if size.value >= to_be_deleted {
+          delete_this_option = match delete_this_option {
+            Some((delete_name, delete_size)) if delete_size <= size.value => Some((delete_name, delete_size)),
+            _                                                             => Some((size.name.to_string(), size.value)) };
+        }
+        if let Some((parent_size, _, _)) = rest.last_mut() {
+          parent_size.subsizes.push(size);
+        } 
+      }
+    }
+  }
+
+  return if let Some((delete_name, delete_size)) = delete_this_option {
+    trace!("Delete directory {delete_name} (size: {delete_size})");
+    delete_size.to_string()
+  } else {
+    "N/A".to_string()
+  }
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+  type Parsed = Vec<Instruction>;
+
+  fn prepare(lines: &[String]) -> Vec<Instruction> { prepare(lines) }
+  fn one(parsed: &Vec<Instruction>, input: &Input) -> String { one(parsed, input) }
+  fn two(parsed: &Vec<Instruction>, input: &Input) -> String { two(parsed, input) }
+}
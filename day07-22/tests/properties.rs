@@ -0,0 +1,57 @@
+use day07_22::{prepare, to_filesystem, Directory};
+use proptest::prelude::*;
+
+/// A directory to be listed in a terminal transcript
+#[derive(Debug, Clone)]
+struct Listing { files: Vec<(String, i64)>, subdirs: Vec<(String, Listing)> }
+
+fn files() -> impl Strategy<Value = Vec<(String, i64)>> {
+  prop::collection::vec(("[a-z]{1,6}(\\.[a-z]{1,3})?", 1..300000i64), 0..4)
+}
+
+fn listing() -> impl Strategy<Value = Listing> {
+  files().prop_map(|files| Listing { files, subdirs: vec![] }).prop_recursive(4, 32, 4, 
+    |inner| (files(), prop::collection::vec(("[a-z]{1,6}", inner), 0..4)).prop_map(|(files, subdirs)| Listing { files, subdirs })
+  )
+}
+
+impl Listing {
+  /// Names are made unique within a directory by appending the position of the entry
+  fn transcript(&self) -> Vec<String> {
+    let mut lines = vec!["$ ls".to_string()];
+    self.subdirs.iter().enumerate().for_each(|(i, (name, _))| lines.push(format!("dir {name}{i}")));
+    self.files.iter().enumerate().for_each(|(i, (name, size))| lines.push(format!("{size} {name}{i}")));
+    self.subdirs.iter().enumerate().for_each(
+      |(i, (name, subdir))| {
+        lines.push(format!("$ cd {name}{i}"));
+        lines.append(&mut subdir.transcript());
+        lines.push("$ cd ..".to_string());
+      }
+    );
+    lines
+  }
+
+  fn size(&self) -> i64 {
+    self.files.iter().map(|(_, size)| size).sum::<i64>() + self.subdirs.iter().map(|(_, subdir)| subdir.size()).sum::<i64>()
+  }
+}
+
+fn file_sizes(directory: &Directory) -> i64 {
+  directory.files.iter().map(|file| file.size).sum::<i64>() + directory.subdirs.iter().map(file_sizes).sum::<i64>()
+}
+
+proptest! {
+  #[test]
+  fn total_size_is_sum_of_files(listing in listing(), unwind in any::<bool>()) {
+    let mut lines = vec!["$ cd /".to_string()];
+    lines.append(&mut listing.transcript());
+    // Transcripts usually end somewhere deep in the tree
+    if !unwind {
+      while lines.last().map(|line| line == "$ cd ..").unwrap_or(false) { lines.pop(); }
+    }
+
+    let (filesystem, total_size) = to_filesystem(&prepare(&lines));
+    prop_assert_eq!(total_size, file_sizes(&filesystem));
+    prop_assert_eq!(total_size, listing.size());
+  }
+}
@@ -0,0 +1,126 @@
+use common::{Input, Solution};
+use log::trace;
+use std::collections::hash_map::RandomState;
+use std::collections::HashSet;
+use std::collections::HashMap;
+use std::cmp::max;
+
+pub type Row    = Vec<u8>;
+pub type Matrix = Vec<Row>;
+
+pub fn prepare(lines: &[String]) -> (Matrix,usize,usize) {
+  let mut width  = 0;
+  let height = lines.len();
+  (lines.iter().fold(
+    Matrix::new(), 
+    |mut acc, line| {
+      acc.push(line.chars().fold(
+        Row::new(),
+        |mut acc, c| {
+          acc.push(c.to_string().parse::<u8>().unwrap());
+          acc
+        }
+      ));
+      width = max(width, line.len());
+      acc
+    }
+  ), width, height)
+}
+
+pub fn one((trees, width, height): &(Matrix,usize,usize), _input: &Input) -> String {
+  let (width, height) = (*width, *height);
+  let mut visible: HashSet<(usize,usize), RandomState> = HashSet::new();
+  
+  vec![(vec![0],      (0..height).collect()),
+       (vec![width-1],(0..height).collect()),
+       ((0..width).collect(),vec![0]       ),
+       ((0..width).collect(),vec![height-1])].into_iter().for_each(|(start_xs, start_ys)| {
+         if let [x] = start_xs[..] {
+           start_ys.into_iter().for_each(|y| {
+             let mut x_iter = if x == 0 { 
+                                (0..width).collect::<Vec<usize>>().into_iter() 
+                              } else { 
+                                (0..width).rev().collect::<Vec<usize>>().into_iter() 
+                              };
+             let start = x_iter.next().unwrap();
+             visible.insert((start,y));
+             x_iter.fold(trees[y][start], |tree, i| {
+              let curr = trees[y][i];
+              if curr > tree {
+                visible.insert((i,y));
+                curr
+              } else {
+                tree 
+              }
+             });
+           });
+         } else if let [y] = start_ys[..] {
+           start_xs.into_iter().for_each(|x| {
+             let mut y_iter = if y == 0 { 
+                                (0..height).collect::<Vec<usize>>().into_iter() 
+                              } else { 
+                                (0..height).rev().collect::<Vec<usize>>().into_iter() 
+                              };
+             let start = y_iter.next().unwrap();
+             visible.insert((x,start));
+             y_iter.fold(trees[start][x], |tree, i| {
+              let curr = trees[i][x];
+              if curr > tree {
+                visible.insert((x,i));
+                curr
+              } else {
+                tree 
+              }
+             });
+           });
+         }
+       });
+       
+  return visible.len().to_string();
+}
+
+pub fn two((trees, width, height): &(Matrix,usize,usize), _input: &Input) -> String {
+  let (width, height) = (*width, *height);
+  let mut scores: HashMap<(usize,usize), u64, RandomState> = HashMap::new();
+
+  #[derive(PartialEq)]
+  enum Dir {Horizontal, Vertical}
+
+  fn view(trees: &Matrix, start_tree: u8, fix: usize, range: std::ops::Range<usize>, rev: bool, dir: Dir) -> u64 {
+    let mut result = 0;
+    for i in if rev {range.rev().collect::<Vec<usize>>()} else {range.collect::<Vec<usize>>()} {
+      result += 1;
+      if start_tree <= (if dir == Dir::Horizontal {trees[fix][i]} else {trees[i][fix]}) {
+        break;
+      }
+    }
+    return result;
+  }
+
+  (1..width-1).into_iter().for_each(|start_x|
+    (1..height-1).into_iter().for_each(|start_y| {
+      let start_tree = trees[start_y][start_x];
+      let right = view(&trees, start_tree, start_y, start_x+1..width , false, Dir::Horizontal);
+      let left  = view(&trees, start_tree, start_y, 0..start_x       , true , Dir::Horizontal);
+      let down  = view(&trees, start_tree, start_x, start_y+1..height, false, Dir::Vertical  );
+      let up    = view(&trees, start_tree, start_x, 0..start_y       , true , Dir::Vertical  );
+
+      scores.insert((start_x+1,start_y+1), right*left*up*down);
+    }
+  ));
+
+  let ((x,y),score) = scores.iter().max_by(|(_,s1),(_,s2)| s1.cmp(s2)).unwrap(); 
+  trace!("Best position is at ({x},{y}) with a score of {score}");
+
+  return score.to_string();
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+  type Parsed = (Matrix,usize,usize);
+
+  fn prepare(lines: &[String]) -> (Matrix,usize,usize) { prepare(lines) }
+  fn one(parsed: &(Matrix,usize,usize), input: &Input) -> String { one(parsed, input) }
+  fn two(parsed: &(Matrix,usize,usize), input: &Input) -> String { two(parsed, input) }
+}
@@ -0,0 +1,124 @@
+use common::{Input, Solution};
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::str::FromStr;
+
+#[derive(Debug)]
+pub enum Direction { Up, Down, Left, Right }
+
+impl Display for Direction {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", match &self {
+      Direction::Up    => "U", 
+      Direction::Down  => "D",
+      Direction::Left  => "L",
+      Direction::Right => "R"
+    })
+  }
+}
+
+impl Direction {
+  pub fn mv(&self, (x,y): Position) -> Position {
+    match &self {
+      Direction::Up    => (x,y+1), 
+      Direction::Down  => (x,y-1),
+      Direction::Left  => (x-1,y),
+      Direction::Right => (x+1,y)
+    }
+  }
+}
+
+impl FromStr for Direction {
+  type Err = ();
+
+  fn from_str(input: &str) -> Result<Direction, Self::Err> {
+      match input {
+          "U" => Ok(Direction::Up),
+          "D" => Ok(Direction::Down),
+          "L" => Ok(Direction::Left),
+          "R" => Ok(Direction::Right),
+          _   => Err(()),
+      }
+  }
+}
+
+#[derive(Debug)]
+pub struct Move { pub dir: Direction, pub steps: i32 }
+
+impl Display for Move {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{} {}", &self.dir, &self.steps)
+  }
+}
+
+pub type Position = (i32, i32);
+
+pub fn pull((hx,hy): Position, (tx,ty): Position) -> Position {
+  let mut result = (tx,ty);
+  let (dx,dy) = (hx-tx,hy-ty);
+  if dx.abs() > 1 {
+    result = (tx+dx.signum(),ty+dy.signum());
+  } else if dy.abs() > 1 {
+    result = (tx+dx.signum(),ty+dy.signum());
+  }
+  return result;
+}
+
+#[derive(Debug)]
+pub struct Rope { pub head: Position, pub tails: Vec<Position> }
+
+pub fn prepare(lines: &[String]) -> Vec<Move> {
+  lines.iter().map(|line| { 
+    if let [dir_string, steps_string] = line.split_whitespace().collect::<Vec<&str>>()[..] {
+      Move { dir: dir_string.parse().unwrap(), steps: steps_string.parse().unwrap() }
+    } else {
+      panic!("Failed parsing move: {line}")
+    }
+  }).collect()
+}
+
+
+pub fn move_rope(moves: &[Move], length: usize) -> HashSet<Position> {
+  let mut visited: HashSet<Position> = HashSet::new();
+
+  moves.iter().fold(
+    Rope { head: (0,0), tails: std::iter::repeat((0,0)).take(length-1).collect() },
+    | rope, Move { dir, steps } | {
+      (0..*steps).fold( 
+        rope, 
+        | Rope { head, mut tails }, _ | {
+          let next_head = dir.mv(head);
+          let next_tail = tails.iter_mut().enumerate().fold(
+            next_head,
+            |prev, (_, curr)| {
+              *curr = pull(prev, *curr);
+              *curr
+            } 
+          );
+          visited.insert(next_tail);
+          Rope { head: next_head, tails: tails }
+        }
+      )
+    }
+  );
+
+  return visited;
+}
+
+pub fn one(moves: &Vec<Move>, _input: &Input) -> String {
+  return move_rope(moves, 2).len().to_string();
+}
+
+pub fn two(moves: &Vec<Move>, _input: &Input) -> String {
+  return move_rope(moves, 10).len().to_string();
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+  type Parsed = Vec<Move>;
+
+  fn prepare(lines: &[String]) -> Vec<Move> { prepare(lines) }
+  fn one(parsed: &Vec<Move>, input: &Input) -> String { one(parsed, input) }
+  fn two(parsed: &Vec<Move>, input: &Input) -> String { two(parsed, input) }
+}
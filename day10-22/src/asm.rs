@@ -0,0 +1,165 @@
+//! Assembles programs for the cpu from text with labels and comments, and turns them back into text
+//!
+//! ```text
+//! ; Move the sprite to the right until Y is 0
+//!         addy 10      ; Y counts the steps
+//! step:   addx 1
+//!         addy -1
+//!         jnz y step
+//!         halt
+//! ```
+
+use crate::{Instruction, Register};
+use std::collections::HashMap;
+use std::fmt::Display;
+
+/// Instructions with the value of X when the program starts
+#[derive(Clone, Debug, PartialEq)]
+pub struct Program { pub instructions: Vec<Instruction>, pub x: i32 }
+
+/// What went wrong on which line of the source, counted from 1
+#[derive(Debug, PartialEq)]
+pub struct AssemblyError { pub line: usize, pub source: String, pub message: String }
+
+impl Display for AssemblyError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "Line {}: {} in `{}`", self.line, self.message, self.source)
+  }
+}
+
+/// A line without its comment, split into its label and its statement
+fn split(line: &str) -> (Option<&str>, &str) {
+  let code = line.split(';').next().unwrap().trim();
+  match code.split_once(':') {
+    Some((label, statement)) => (Some(label.trim()), statement.trim()),
+    None                     => (None, code)
+  }
+}
+
+/// Assemble the source, where every line has an optional `label:`, an optional instruction or directive, and an optional `; comment`.
+/// `jnz` jumps to labels or instruction indices.
+/// Only the extended mode allows the extended instructions and the directive `.x v`, which sets X at the start.
+pub fn assemble(lines: &[String], extended: bool) -> Result<Program, AssemblyError> {
+  let error = |i: usize, message: String| AssemblyError { line: i+1, source: lines[i].trim().to_string(), message };
+
+  // Find the instruction index of every label first, so that jumps may go forward
+  let mut labels: HashMap<&str, usize> = HashMap::new();
+  let mut count = 0;
+  for (i, line) in lines.iter().enumerate() {
+    let (label, statement) = split(line);
+    if let Some(label) = label {
+      if label.is_empty() || label.contains(char::is_whitespace) { return Err(error(i, format!("Invalid label `{label}`"))); }
+      if labels.insert(label, count).is_some() { return Err(error(i, format!("Label `{label}` is defined twice"))); }
+    }
+    if !statement.is_empty() && !statement.starts_with('.') { count += 1; }
+  }
+
+  let mut program = Program { instructions: vec![], x: 1 };
+  for (i, line) in lines.iter().enumerate() {
+    let (_, statement) = split(line);
+    let words: Vec<&str> = statement.split_whitespace().collect();
+    let instruction = match words[..] {
+      []                              => continue,
+      [".x", v_str] if extended       => {
+        program.x = v_str.parse().map_err(|_| error(i, format!("`{v_str}` is not a number")))?;
+        continue;
+      },
+      ["jnz", r_str, target]          => {
+        let register = r_str.parse::<Register>().map_err(|err| error(i, err))?;
+        match (labels.get(target).copied(), target.parse::<usize>()) {
+          (Some(index), _) | (None, Ok(index)) => Instruction::Jnz(register, index),
+          _                                     => return Err(error(i, format!("Unknown label `{target}`")))
+        }
+      },
+      _                               => statement.parse::<Instruction>().map_err(|err| error(i, err))?
+    };
+    if instruction.is_extended() && !extended {
+      return Err(error(i, format!("`{}` is only available in extended mode", words[0])));
+    }
+    if let Instruction::Jnz(_, target) = instruction {
+      if target >= count { return Err(error(i, format!("Jump to instruction {target}, but there are only {count}"))); }
+    }
+    program.instructions.push(instruction);
+  }
+
+  return Ok(program);
+}
+
+/// The source of the program, with a label `l<index>` at every jump target
+pub fn disassemble(program: &Program) -> String {
+  let targets: Vec<usize> = program.instructions.iter().filter_map(
+    |instruction| if let Instruction::Jnz(_, target) = instruction { Some(*target) } else { None }
+  ).collect();
+
+  let start = if program.x == 1 { String::new() } else { format!(".x {}\n", program.x) };
+  program.instructions.iter().enumerate().fold(
+    start,
+    |acc, (i, instruction)| {
+      let label = if targets.contains(&i) { format!("l{i}:") } else { String::new() };
+      let line  = match instruction {
+        Instruction::Jnz(register, target) => format!("jnz {register} l{target}"),
+        instruction                        => instruction.to_string()
+      };
+      format!("{acc}{label:<6}{line}\n")
+    }
+  )
+}
+
+/// The source of a program drawing the picture of `#` and `.` on a CRT of the given width.
+/// Every instruction takes one cycle and moves the sprite for the next pixel, if it has to.
+pub fn draw(picture: &str, width: usize) -> String {
+  let pixels: Vec<bool> = picture.lines().filter(|line| !line.is_empty()).flat_map(
+    |line| (0..width).map(move |x| line.chars().nth(x) == Some('#'))
+  ).collect();
+  // Far enough from every position that the sprite never shows
+  let hidden = -3;
+  let sprite = |i: usize, lit: bool| if lit { (i % width) as i32 + 1 } else { hidden };
+  let shows  = |x: i32, i: usize| (x - (i % width) as i32).abs() <= 1;
+
+  let mut x = sprite(0, pixels.first() == Some(&true));
+  let mut source = format!(".x {x}\n");
+  for i in 1..pixels.len() {
+    if shows(x, i) == pixels[i] {
+      source.push_str("noop\n");
+    } else {
+      x = sprite(i, pixels[i]);
+      source.push_str(&format!("setx {x}\n"));
+    }
+  }
+  source.push_str("halt\n");
+
+  return source;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Cpu, Crt};
+
+    fn lines(source: &str) -> Vec<String> { source.lines().map(|line| line.to_string()).collect() }
+
+    #[test]
+    fn labels_and_round_trip() {
+        let source = lines("; count down\n.x 5\n  addy 3\nloop: addx -1 ; move left\n  addy -1\n  jnz y loop\n  halt");
+        let program = assemble(&source, true).unwrap();
+        assert_eq!(program.x, 5);
+        assert_eq!(program.instructions[3], Instruction::Jnz(Register::Y, 1));
+        assert_eq!(assemble(&lines(&disassemble(&program)), true), Ok(program));
+    }
+
+    #[test]
+    fn errors_report_the_line() {
+        let error = assemble(&lines("noop\naddx 1\naddy 2"), false).unwrap_err();
+        assert_eq!(error.line, 3);
+        assert_eq!(error.to_string(), "Line 3: `addy` is only available in extended mode in `addy 2`");
+        assert_eq!(assemble(&lines("noop\njnz x nowhere"), true).unwrap_err().line, 2);
+    }
+
+    #[test]
+    fn draw_any_picture() {
+        let picture = ".#..#\n#.##.\n.....\n#####";
+        let mut crt = Crt::new(5);
+        Cpu::load(assemble(&lines(&draw(picture, 5)), true).unwrap()).run(&mut [&mut crt]);
+        assert_eq!(crt.screen, format!("\n{picture}"));
+    }
+}
@@ -0,0 +1,415 @@
+pub mod asm;
+pub mod ocr;
+
+use common::{Input, Solution};
+use log::{trace, warn};
+use std::cmp::min;
+use std::fmt::Display;
+use std::io::{self, BufRead, Write};
+use std::str::FromStr;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Register { X, Y }
+
+impl Display for Register {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match &self {
+      Register::X => write!(f, "x"),
+      Register::Y => write!(f, "y")
+    }
+  }
+}
+
+impl FromStr for Register {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s {
+      "x" => Ok(Register::X),
+      "y" => Ok(Register::Y),
+      _   => Err(format!("Unknown register `{s}`"))
+    }
+  }
+}
+
+/// The instructions of the puzzle, and those of the extended mode:
+/// `addy v` adds to register Y, `setx v` sets register X, 
+/// `jnz r i` jumps to instruction i unless register r is 0, and `halt` stops the program
+#[derive(Clone, Debug, PartialEq)]
+pub enum Instruction { Noop, Addx(i32), Addy(i32), Setx(i32), Jnz(Register, usize), Halt }
+
+impl Display for Instruction {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+      match &self {
+        Instruction::Noop        => write!(f, "noop"),
+        Instruction::Addx(v)     => write!(f, "addx {v}"),
+        Instruction::Addy(v)     => write!(f, "addy {v}"),
+        Instruction::Setx(v)     => write!(f, "setx {v}"),
+        Instruction::Jnz(r, i)   => write!(f, "jnz {r} {i}"),
+        Instruction::Halt        => write!(f, "halt")
+      }
+  }
+}
+
+impl Instruction {
+  pub fn cycles(&self) -> i32 {
+    match &self {
+      Instruction::Addx(_) | Instruction::Addy(_) => 2,
+      _                                           => 1
+    }
+  }
+
+  pub fn is_extended(&self) -> bool { !matches!(self, Instruction::Noop | Instruction::Addx(_)) }
+}
+
+impl FromStr for Instruction {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+      let number = |v_str: &str| v_str.parse::<i32>().map_err(|_| format!("`{v_str}` is not a number"));
+      match s.split_whitespace().collect::<Vec<&str>>()[..] {
+        ["noop"]             => Ok(Instruction::Noop),
+        ["addx", v_str]      => Ok(Instruction::Addx(number(v_str)?)),
+        ["addy", v_str]      => Ok(Instruction::Addy(number(v_str)?)),
+        ["setx", v_str]      => Ok(Instruction::Setx(number(v_str)?)),
+        ["jnz", r_str, i_str] => Ok(Instruction::Jnz(r_str.parse()?, i_str.parse().map_err(|_| format!("`{i_str}` is not an instruction index"))?)),
+        ["halt"]             => Ok(Instruction::Halt),
+        _                    => Err(format!("Unknown instruction `{s}`"))
+      }
+  }
+}
+
+/// Assemble the program with the extended instructions, which are only run if `--extended` is given
+pub fn prepare(lines: &[String]) -> asm::Program {
+  asm::assemble(lines, true).unwrap_or_else(|err| panic!("{err}"))
+}
+
+/// Something that looks at the registers during every cycle
+pub trait Observer {
+  fn during(&mut self, cycle: usize, cpu: &Cpu);
+}
+
+/// The sum of the signal strengths during the cycles `first`, `first+every`, ...
+pub struct SignalStrength { pub first: usize, pub every: usize, pub sum: i32 }
+
+impl SignalStrength {
+  pub fn new(first: usize, every: usize) -> SignalStrength { SignalStrength { first, every, sum: 0 } }
+}
+
+impl Observer for SignalStrength {
+  fn during(&mut self, cycle: usize, cpu: &Cpu) {
+    if cycle >= self.first && (cycle - self.first) % self.every == 0 {
+      trace!("Cycle {cycle}: Multiply by {}", cpu.x);
+      self.sum += cycle as i32 * cpu.x;
+    }
+  }
+}
+
+/// Draws one pixel per cycle, lit if the sprite of width 3 around `x` covers it
+pub struct Crt { pub width: usize, pub screen: String }
+
+impl Crt {
+  pub fn new(width: usize) -> Crt { Crt { width, screen: String::new() } }
+}
+
+impl Observer for Crt {
+  fn during(&mut self, cycle: usize, cpu: &Cpu) {
+    let scan_pos = ((cycle - 1) % self.width) as i32;
+    if scan_pos == 0 { self.screen.push('\n'); }
+    self.screen.push(if (cpu.x - scan_pos).abs() <= 1 { '#' } else { '.' });
+  }
+}
+
+/// The default of `--max-cycles`, far more than any program of the puzzle needs
+pub const MAX_CYCLES: usize = 100_000;
+
+/// Runs the program one cycle at a time
+pub struct Cpu {
+  pub program   : Vec<Instruction>,
+  /// The X register
+  pub x         : i32,
+  /// The Y register of the extended mode
+  pub y         : i32,
+  /// The cycle that runs next, starting at 1
+  pub cycle     : usize,
+  /// The index of the instruction that runs next
+  pub pc        : usize,
+  /// How many cycles the current instruction already ran
+  pub busy      : i32,
+  /// Whether a `halt` instruction ran
+  pub stopped   : bool,
+  /// The last cycle that `run` runs, since a program may loop forever
+  pub max_cycles: usize
+}
+
+impl Cpu {
+  pub fn new(program: Vec<Instruction>) -> Cpu { Cpu { program, x: 1, y: 0, cycle: 1, pc: 0, busy: 0, stopped: false, max_cycles: MAX_CYCLES } }
+
+  pub fn load(program: asm::Program) -> Cpu { Cpu { x: program.x, ..Cpu::new(program.instructions) } }
+
+  pub fn halted(&self) -> bool { self.stopped || self.pc >= self.program.len() }
+
+  /// Whether the program still runs after `max_cycles`
+  pub fn exhausted(&self) -> bool { !self.halted() && self.cycle > self.max_cycles }
+
+  /// The value of the register
+  pub fn get(&self, register: Register) -> i32 {
+    match register {
+      Register::X => self.x,
+      Register::Y => self.y
+    }
+  }
+
+  /// The register of the given name, if there is one
+  pub fn register(&self, name: &str) -> Option<i32> { name.parse::<Register>().ok().map(|register| self.get(register)) }
+
+  /// Run one cycle: the observers see the registers during the cycle, 
+  /// and an instruction changes them at the end of its last cycle
+  pub fn step(&mut self, observers: &mut [&mut dyn Observer]) {
+    if self.halted() { return; }
+    observers.iter_mut().for_each(|observer| observer.during(self.cycle, self));
+
+    self.busy += 1;
+    if self.busy == self.program[self.pc].cycles() {
+      self.busy = 0;
+      self.pc  += 1;
+      match self.program[self.pc-1] {
+        Instruction::Noop                  => (),
+        Instruction::Addx(v)               => self.x += v,
+        Instruction::Addy(v)               => self.y += v,
+        Instruction::Setx(v)               => self.x = v,
+        Instruction::Jnz(register, target) => if self.get(register) != 0 { self.pc = target },
+        Instruction::Halt                  => self.stopped = true
+      }
+    }
+    self.cycle += 1;
+  }
+
+  /// Run until the program halts, or panic once it exceeds `max_cycles`
+  pub fn run(&mut self, observers: &mut [&mut dyn Observer]) {
+    while !self.halted() {
+      if self.exhausted() {
+        panic!("The program still runs after {} cycles, at instruction {} `{}`, raise the limit with --max-cycles", self.max_cycles, self.pc, self.program[self.pc]);
+      }
+      self.step(observers);
+    }
+  }
+}
+
+impl Display for Cpu {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self.program.get(self.pc).filter(|_| !self.stopped) {
+      Some(instruction) => write!(f, "cycle {:>3}, instruction {:>3} ({instruction}, cycle {} of {}), x={}", self.cycle, self.pc, self.busy+1, instruction.cycles(), self.x),
+      None              => write!(f, "cycle {:>3}, halted, x={}", self.cycle, self.x)
+    }?;
+    if self.y != 0 { write!(f, ", y={}", self.y) } else { Ok(()) }
+  }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Breakpoint { Cycle(usize), Instruction(usize) }
+
+impl Breakpoint {
+  pub fn hit(&self, cpu: &Cpu) -> bool {
+    match self {
+      Breakpoint::Cycle(cycle)       => cpu.cycle == *cycle,
+      Breakpoint::Instruction(index) => cpu.pc == *index && cpu.busy == 0
+    }
+  }
+}
+
+impl Display for Breakpoint {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Breakpoint::Cycle(cycle)       => write!(f, "cycle {cycle}"),
+      Breakpoint::Instruction(index) => write!(f, "instruction {index}")
+    }
+  }
+}
+
+pub const DEBUG_HELP: &str = "\
+step [n]            run n cycles (default 1)
+continue            run until a breakpoint is hit or the program halts
+break cycle <n>     stop before cycle n
+break inst <n>      stop before instruction n starts
+watch <register>    report every change of the register
+delete              remove all breakpoints and watches
+print               show the registers
+list                show the instructions around the current one
+quit                stop debugging and run to the end
+help                show this help";
+
+/// An interactive debugger on top of the cpu
+#[derive(Default)]
+pub struct Debugger { pub breakpoints: Vec<Breakpoint>, pub watches: Vec<String> }
+
+impl Debugger {
+  /// Run one cycle and report the watched registers that changed
+  fn step(&self, cpu: &mut Cpu, observers: &mut [&mut dyn Observer], out: &mut impl Write) {
+    let before: Vec<Option<i32>> = self.watches.iter().map(|name| cpu.register(name)).collect();
+    cpu.step(observers);
+    self.watches.iter().zip(before).for_each(
+      |(name, before)| {
+        let after = cpu.register(name);
+        if after != before {
+          writeln!(out, "{name}: {} -> {} (end of cycle {})", before.unwrap_or_default(), after.unwrap_or_default(), cpu.cycle-1).unwrap();
+        }
+      }
+    );
+  }
+
+  /// Read commands until the input ends or `quit`, then run the rest of the program
+  pub fn repl(&mut self, cpu: &mut Cpu, observers: &mut [&mut dyn Observer], input: impl BufRead, mut out: impl Write) {
+    writeln!(out, "{cpu}\nType `help` for the commands").unwrap();
+    let mut lines = input.lines();
+
+    loop {
+      write!(out, "(cpu) ").unwrap();
+      out.flush().unwrap();
+      let Some(Ok(line)) = lines.next() else { break; };
+
+      match line.split_whitespace().collect::<Vec<&str>>()[..] {
+        []                                 => continue,
+        ["s" | "step"]                     => self.step(cpu, observers, &mut out),
+        ["s" | "step", n]                  => match n.parse::<usize>() {
+          Ok(n) => (0..n).for_each(|_| self.step(cpu, observers, &mut out)),
+          Err(_) => { writeln!(out, "Not a number of cycles: {n}").unwrap(); continue; }
+        },
+        ["c" | "continue"]                 => {
+          self.step(cpu, observers, &mut out);
+          while !cpu.halted() {
+            if cpu.exhausted() {
+              writeln!(out, "Stopped after the limit of {} cycles", cpu.max_cycles).unwrap();
+              break;
+            }
+            if let Some(breakpoint) = self.breakpoints.iter().find(|breakpoint| breakpoint.hit(cpu)) {
+              writeln!(out, "Breakpoint at {breakpoint}").unwrap();
+              break;
+            }
+            self.step(cpu, observers, &mut out);
+          }
+        },
+        ["b" | "break", kind, n]           => {
+          let breakpoint = match (kind, n.parse::<usize>()) {
+            ("cycle", Ok(n))       => Breakpoint::Cycle(n),
+            ("inst" , Ok(n))       => Breakpoint::Instruction(n),
+            _                      => { writeln!(out, "Usage: break cycle <n> | break inst <n>").unwrap(); continue; }
+          };
+          writeln!(out, "Breakpoint {} at {breakpoint}", self.breakpoints.len()+1).unwrap();
+          self.breakpoints.push(breakpoint);
+          continue;
+        },
+        ["w" | "watch", name]              => {
+          match cpu.register(name) {
+            Some(value) => { writeln!(out, "Watching {name} = {value}").unwrap(); self.watches.push(name.to_string()); },
+            None        => writeln!(out, "Unknown register {name}").unwrap()
+          }
+          continue;
+        },
+        ["d" | "delete"]                   => { self.breakpoints.clear(); self.watches.clear(); continue; },
+        ["p" | "print"]                    => (),
+        ["l" | "list"]                     => {
+          (cpu.pc.saturating_sub(3)..min(cpu.pc+4, cpu.program.len())).for_each(
+            |i| writeln!(out, "{} {i:>4}: {}", if i == cpu.pc { '>' } else { ' ' }, cpu.program[i]).unwrap()
+          );
+          continue;
+        },
+        ["q" | "quit"]                     => break,
+        ["h" | "help"]                     => { writeln!(out, "{DEBUG_HELP}").unwrap(); continue; },
+        _                                  => { writeln!(out, "Unknown command `{line}`, type `help` for the commands").unwrap(); continue; }
+      }
+      writeln!(out, "{cpu}").unwrap();
+    }
+
+    cpu.run(observers);
+  }
+}
+
+/// The program, unless it needs the extended instructions without `--extended` being given
+pub fn checked(program: &asm::Program, input: &Input) -> asm::Program {
+  if !input.flag("extended") {
+    if let Some(i) = program.instructions.iter().position(|instruction| instruction.is_extended()) {
+      panic!("Instruction {i} `{}` is only available in extended mode, given by --extended", program.instructions[i]);
+    }
+    if program.x != 1 { panic!("`.x` is only available in extended mode, given by --extended"); }
+  }
+  return program.clone();
+}
+
+/// Run the program with the observers for at most `--max-cycles` cycles, in the debugger if `--debug` is given
+pub fn execute(input: &Input, program: asm::Program, observers: &mut [&mut dyn Observer]) {
+  let mut cpu = Cpu { max_cycles: input.option("max-cycles").unwrap_or(MAX_CYCLES), ..Cpu::load(program) };
+  if input.flag("debug") {
+    Debugger::default().repl(&mut cpu, observers, io::stdin().lock(), io::stdout());
+  } else {
+    cpu.run(observers);
+  }
+}
+
+pub fn one(program: &asm::Program, input: &Input) -> String {
+  let mut signal = SignalStrength::new(20, 40);
+  execute(input, checked(program, input), &mut [&mut signal]);
+
+  return signal.sum.to_string();
+}
+
+pub fn two(program: &asm::Program, input: &Input) -> String {
+  let mut crt = Crt::new(40);
+  execute(input, checked(program, input), &mut [&mut crt]);
+  trace!("Screen:{}", crt.screen);
+
+  return match ocr::read(&crt.screen) {
+    Ok(text) => text,
+    Err(err) => {
+      warn!("{err}");
+      crt.screen
+    }
+  };
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+  type Parsed = asm::Program;
+
+  fn prepare(lines: &[String]) -> asm::Program { prepare(lines) }
+  fn one(parsed: &asm::Program, input: &Input) -> String { one(parsed, input) }
+  fn two(parsed: &asm::Program, input: &Input) -> String { two(parsed, input) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn debugger_session() {
+        let source: Vec<String> = ["addy 2", "loop: addx 3", "addy -1", "jnz y loop"].iter().map(|line| line.to_string()).collect();
+        let mut cpu = Cpu::load(asm::assemble(&source, true).unwrap());
+        let commands = "watch x\nwatch z\nbreak inst 3\ncontinue\nstep 2\ncontinue\ndelete\nstep\nfrobnicate\nquit\n";
+        let mut out = vec![];
+        Debugger::default().repl(&mut cpu, &mut [], commands.as_bytes(), &mut out);
+
+        let transcript: Vec<String> = String::from_utf8(out).unwrap().split("(cpu) ").map(|reply| reply.to_string()).collect();
+        assert_eq!(transcript[1..], [
+            "Watching x = 1\n",
+            "Unknown register z\n",
+            "Breakpoint 1 at instruction 3\n",
+            "x: 1 -> 4 (end of cycle 4)\nBreakpoint at instruction 3\ncycle   7, instruction   3 (jnz y 1, cycle 1 of 1), x=4, y=1\n",
+            "cycle   9, instruction   1 (addx 3, cycle 2 of 2), x=4, y=1\n",
+            "x: 4 -> 7 (end of cycle 9)\nBreakpoint at instruction 3\ncycle  12, instruction   3 (jnz y 1, cycle 1 of 1), x=7\n",
+            "",
+            "cycle  13, halted, x=7\n",
+            "Unknown command `frobnicate`, type `help` for the commands\n",
+            ""
+        ]);
+        assert!(cpu.halted());
+    }
+
+    #[test]
+    #[should_panic(expected = "The program still runs after 50 cycles, at instruction 1 `jnz x 1`")]
+    fn endless_loop_stops() {
+        let source = vec!["noop".to_string(), "l: jnz x l".to_string()];
+        let input  = Input::new(common::args::Part::One, vec![]).with_options(&["--max-cycles", "50", "--extended"]);
+        execute(&input, asm::assemble(&source, true).unwrap(), &mut []);
+    }
+}
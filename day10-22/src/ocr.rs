@@ -0,0 +1,156 @@
+//! Reads the letters drawn on the CRT in one of the fonts used by AoC
+
+use std::fmt::Display;
+
+/// A font with glyphs of a fixed height, written with `#` for lit and `.` for dark pixels
+pub struct Font { pub height: usize, pub glyphs: &'static [(char, [&'static str; 10])] }
+
+/// The 4×6 font, e.g. of 2016 day 8, 2019 day 8 and 2022 day 10, where only `Y` is 5 pixels wide
+pub const SMALL: Font = Font { height: 6, glyphs: &[
+  ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#", "", "", "", ""]),
+  ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###.", "", "", "", ""]),
+  ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##.", "", "", "", ""]),
+  ('E', ["####", "#...", "###.", "#...", "#...", "####", "", "", "", ""]),
+  ('F', ["####", "#...", "###.", "#...", "#...", "#...", "", "", "", ""]),
+  ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###", "", "", "", ""]),
+  ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#", "", "", "", ""]),
+  ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###", "", "", "", ""]),
+  ('J', ["..##", "...#", "...#", "...#", "#..#", ".##.", "", "", "", ""]),
+  ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#", "", "", "", ""]),
+  ('L', ["#...", "#...", "#...", "#...", "#...", "####", "", "", "", ""]),
+  ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##.", "", "", "", ""]),
+  ('P', ["###.", "#..#", "#..#", "###.", "#...", "#...", "", "", "", ""]),
+  ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#", "", "", "", ""]),
+  ('S', [".###", "#...", "#...", ".##.", "...#", "###.", "", "", "", ""]),
+  ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##.", "", "", "", ""]),
+  ('Y', ["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#..", "", "", "", ""]),
+  ('Z', ["####", "...#", "..#.", ".#..", "#...", "####", "", "", "", ""])
+]};
+
+/// The 6×10 font of 2018 day 10
+pub const LARGE: Font = Font { height: 10, glyphs: &[
+  ('A', ["..##..", ".#..#.", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#", "#....#"]),
+  ('B', ["#####.", "#....#", "#....#", "#....#", "#####.", "#....#", "#....#", "#....#", "#....#", "#####."]),
+  ('C', [".####.", "#....#", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#....#", ".####."]),
+  ('E', ["######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....", "#.....", "######"]),
+  ('F', ["######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....", "#.....", "#....."]),
+  ('G', [".####.", "#....#", "#.....", "#.....", "#.....", "#..###", "#....#", "#....#", "#...##", ".###.#"]),
+  ('H', ["#....#", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#", "#....#", "#....#"]),
+  ('J', ["...###", "....#.", "....#.", "....#.", "....#.", "....#.", "....#.", "#...#.", "#...#.", ".###.."]),
+  ('K', ["#....#", "#...#.", "#..#..", "#.#...", "##....", "##....", "#.#...", "#..#..", "#...#.", "#....#"]),
+  ('L', ["#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "######"]),
+  ('N', ["#....#", "##...#", "##...#", "#.#..#", "#.#..#", "#..#.#", "#..#.#", "#...##", "#...##", "#....#"]),
+  ('P', ["#####.", "#....#", "#....#", "#....#", "#####.", "#.....", "#.....", "#.....", "#.....", "#....."]),
+  ('R', ["#####.", "#....#", "#....#", "#....#", "#####.", "#..#..", "#...#.", "#...#.", "#....#", "#....#"]),
+  ('X', ["#....#", "#....#", ".#..#.", ".#..#.", "..##..", "..##..", ".#..#.", ".#..#.", "#....#", "#....#"]),
+  ('Z', ["######", ".....#", ".....#", "....#.", "...#..", "..#...", ".#....", "#.....", "#.....", "######"])
+]};
+
+/// The pixels of a glyph or a part of the screen, without the dark columns on its left and right
+fn trim(rows: &[Vec<bool>]) -> Vec<Vec<bool>> {
+  let width = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+  let lit   = |x: usize| rows.iter().any(|row| row.get(x) == Some(&true));
+  match ((0..width).find(|x| lit(*x)), (0..width).rev().find(|x| lit(*x))) {
+    (Some(first), Some(last)) => rows.iter().map(|row| (first..=last).map(|x| row.get(x) == Some(&true)).collect()).collect(),
+    _                         => vec![]
+  }
+}
+
+impl Font {
+  /// The character drawn by the pixels, if the font has it
+  pub fn recognize(&self, pixels: &[Vec<bool>]) -> Option<char> {
+    let pixels = trim(pixels);
+    self.glyphs.iter().find(
+      |(_, rows)| trim(&rows[..self.height].iter().map(|row| row.chars().map(|c| c == '#').collect()).collect::<Vec<Vec<bool>>>()) == pixels
+    ).map(|(c, _)| *c)
+  }
+}
+
+/// The text read so far, with `?` for every glyph that was not recognized, and the columns where those start
+#[derive(Debug, PartialEq)]
+pub struct Unrecognized { pub text: String, pub columns: Vec<usize> }
+
+impl Display for Unrecognized {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "Unrecognized glyphs at columns {} in `{}`",
+      self.columns.iter().map(|column| column.to_string()).collect::<Vec<String>>().join(", "), self.text)
+  }
+}
+
+/// Read the text on a screen of `#` and `.`, choosing the font by the height of the screen.
+/// Glyphs are separated by at least one dark column.
+/// A screen of any other height is one unrecognized glyph at column 0.
+pub fn read(screen: &str) -> Result<String, Unrecognized> {
+  let rows: Vec<Vec<bool>> = screen.lines().filter(|line| !line.trim().is_empty()).map(|line| line.chars().map(|c| c == '#').collect()).collect();
+  let font = match rows.len() {
+    6  => &SMALL,
+    10 => &LARGE,
+    _  => return Err(Unrecognized { text: String::new(), columns: vec![0] })
+  };
+
+  let width = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+  let lit   = |x: usize| rows.iter().any(|row| row.get(x) == Some(&true));
+  let mut result = Unrecognized { text: String::new(), columns: vec![] };
+  let mut x = 0;
+  while x < width {
+    if !lit(x) { x += 1; continue; }
+    let start  = x;
+    while x < width && lit(x) { x += 1; }
+    let glyph: Vec<Vec<bool>> = rows.iter().map(|row| (start..x).map(|x| row.get(x) == Some(&true)).collect()).collect();
+    match font.recognize(&glyph) {
+      Some(c) => result.text.push(c),
+      None    => { result.text.push('?'); result.columns.push(start); }
+    }
+  }
+
+  return if result.columns.is_empty() { Ok(result.text) } else { Err(result) };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn read_small_font() {
+        let screen = "
+###..#..#.###....##.###..###..#.....##..
+#..#.#.#..#..#....#.#..#.#..#.#....#..#.
+#..#.##...#..#....#.###..#..#.#....#..#.
+###..#.#..###.....#.#..#.###..#....####.
+#.#..#.#..#....#..#.#..#.#....#....#..#.
+#..#.#..#.#.....##..###..#....####.#..#.";
+        assert_eq!(read(screen), Ok("RKPJBPLA".to_string()));
+    }
+
+    #[test]
+    fn read_five_wide_y() {
+        let screen = "
+#...#.#..#.####
+#...#.#..#....#
+.#.#..#..#...#.
+..#...#..#..#..
+..#...#..#.#...
+..#....##..####";
+        assert_eq!(read(screen), Ok("YUZ".to_string()));
+    }
+
+    #[test]
+    fn report_unrecognized_glyph() {
+        let screen = "
+#..#..#.#.
+#..#...#..
+####..#.#.
+#..#...#..
+#..#..#.#.
+#..#...#..";
+        assert_eq!(read(screen), Err(Unrecognized { text: "H?".to_string(), columns: vec![6] }));
+    }
+
+    #[test]
+    fn read_large_font() {
+        let rows = (0..10).map(
+          |y| ['H', 'X', 'Z'].iter().map(|c| LARGE.glyphs.iter().find(|(g, _)| g == c).unwrap().1[y]).collect::<Vec<&str>>().join("..")
+        ).collect::<Vec<String>>();
+        assert_eq!(read(&rows.join("\n")), Ok("HXZ".to_string()));
+    }
+}
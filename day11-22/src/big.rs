@@ -0,0 +1,173 @@
+//! A small signed integer of arbitrary precision, enough to follow the real worry levels
+
+use std::cmp::Ordering;
+use std::fmt::Display;
+
+/// Sign and magnitude, with the magnitude in base 2^32 from the least significant digit.
+/// There are no leading zero digits and 0 is never negative.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Big { negative: bool, digits: Vec<u32> }
+
+fn trim(mut digits: Vec<u32>) -> Vec<u32> {
+  while digits.last() == Some(&0) { digits.pop(); }
+  return digits;
+}
+
+fn compare(a: &[u32], b: &[u32]) -> Ordering {
+  a.len().cmp(&b.len()).then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+fn add(a: &[u32], b: &[u32]) -> Vec<u32> {
+  let mut result = Vec::with_capacity(a.len().max(b.len()) + 1);
+  let mut carry  = 0u64;
+  for i in 0..a.len().max(b.len()) {
+    let sum = *a.get(i).unwrap_or(&0) as u64 + *b.get(i).unwrap_or(&0) as u64 + carry;
+    result.push(sum as u32);
+    carry = sum >> 32;
+  }
+  result.push(carry as u32);
+  return trim(result);
+}
+
+/// `a - b` for `a >= b`
+fn sub(a: &[u32], b: &[u32]) -> Vec<u32> {
+  let mut result = Vec::with_capacity(a.len());
+  let mut borrow = 0i64;
+  for i in 0..a.len() {
+    let mut difference = a[i] as i64 - *b.get(i).unwrap_or(&0) as i64 - borrow;
+    borrow = if difference < 0 { difference += 1 << 32; 1 } else { 0 };
+    result.push(difference as u32);
+  }
+  return trim(result);
+}
+
+fn mul(a: &[u32], b: &[u32]) -> Vec<u32> {
+  let mut result = vec![0u32; a.len() + b.len()];
+  for (i, x) in a.iter().enumerate() {
+    let mut carry = 0u64;
+    for (j, y) in b.iter().enumerate() {
+      let product = *x as u64 * *y as u64 + result[i+j] as u64 + carry;
+      result[i+j] = product as u32;
+      carry = product >> 32;
+    }
+    result[i + b.len()] = carry as u32;
+  }
+  return trim(result);
+}
+
+/// Quotient and remainder of `a / b` for `b > 0`, digit by digit if `b` has a single digit, else bit by bit
+fn divide(a: &[u32], b: &[u32]) -> (Vec<u32>, Vec<u32>) {
+  if let [divisor] = b {
+    let mut quotient  = vec![0u32; a.len()];
+    let mut remainder = 0u64;
+    for i in (0..a.len()).rev() {
+      let current = (remainder << 32) | a[i] as u64;
+      quotient[i] = (current / *divisor as u64) as u32;
+      remainder   = current % *divisor as u64;
+    }
+    return (trim(quotient), trim(vec![remainder as u32]));
+  }
+
+  let mut quotient  = vec![0u32; a.len()];
+  let mut remainder: Vec<u32> = vec![];
+  for bit in (0..a.len() * 32).rev() {
+    remainder = add(&remainder, &remainder);
+    if (a[bit / 32] >> (bit % 32)) & 1 == 1 { remainder = add(&remainder, &[1]); }
+    if compare(&remainder, b) != Ordering::Less {
+      remainder = sub(&remainder, b);
+      quotient[bit / 32] |= 1 << (bit % 32);
+    }
+  }
+  return (trim(quotient), remainder);
+}
+
+impl Big {
+  fn new(negative: bool, digits: Vec<u32>) -> Big {
+    let digits = trim(digits);
+    Big { negative: negative && !digits.is_empty(), digits }
+  }
+
+  pub fn is_zero(&self) -> bool { self.digits.is_empty() }
+
+  pub fn is_negative(&self) -> bool { self.negative }
+
+  /// The number of bits of the magnitude
+  pub fn bits(&self) -> usize {
+    self.digits.last().map_or(0, |top| 32 * self.digits.len() - top.leading_zeros() as usize)
+  }
+
+  pub fn neg(&self) -> Big { Big::new(!self.negative, self.digits.clone()) }
+
+  pub fn add(&self, other: &Big) -> Big {
+    if self.negative == other.negative {
+      return Big::new(self.negative, add(&self.digits, &other.digits));
+    }
+    match compare(&self.digits, &other.digits) {
+      Ordering::Less => Big::new(other.negative, sub(&other.digits, &self.digits)),
+      _              => Big::new(self.negative, sub(&self.digits, &other.digits))
+    }
+  }
+
+  pub fn sub(&self, other: &Big) -> Big { self.add(&other.neg()) }
+
+  pub fn mul(&self, other: &Big) -> Big {
+    Big::new(self.negative != other.negative, mul(&self.digits, &other.digits))
+  }
+
+  /// Quotient and remainder like for `i64`: rounded towards 0, with the remainder taking the sign of `self`
+  pub fn div_rem(&self, other: &Big) -> Option<(Big, Big)> {
+    if other.is_zero() { return None; }
+    let (quotient, remainder) = divide(&self.digits, &other.digits);
+    Some((Big::new(self.negative != other.negative, quotient), Big::new(self.negative, remainder)))
+  }
+}
+
+impl From<i64> for Big {
+  fn from(value: i64) -> Big {
+    let magnitude = value.unsigned_abs();
+    Big::new(value < 0, vec![magnitude as u32, (magnitude >> 32) as u32])
+  }
+}
+
+impl Display for Big {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    // Split off 9 decimal digits at a time
+    let mut chunks: Vec<u32> = vec![];
+    let mut rest = self.digits.clone();
+    while !rest.is_empty() {
+      let (quotient, remainder) = divide(&rest, &[1_000_000_000]);
+      chunks.push(*remainder.first().unwrap_or(&0));
+      rest = quotient;
+    }
+    write!(f, "{}{}", if self.negative { "-" } else { "" }, chunks.last().unwrap_or(&0))?;
+    chunks.iter().rev().skip(1).try_for_each(|chunk| write!(f, "{chunk:09}"))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_i64() {
+        let values = [0, 1, -1, 7, -13, 4294967295, 4294967296, -98765432123, i32::MAX as i64 * 3];
+        for x in values {
+            for y in values {
+                let (a, b) = (Big::from(x), Big::from(y));
+                assert_eq!(a.add(&b), Big::from(x + y));
+                assert_eq!(a.sub(&b), Big::from(x - y));
+                if let Some(product) = x.checked_mul(y) { assert_eq!(a.mul(&b), Big::from(product)); }
+                assert_eq!(a.div_rem(&b), x.checked_div(y).map(|q| (Big::from(q), Big::from(x % y))));
+            }
+        }
+    }
+
+    #[test]
+    fn exceeds_i64() {
+        let big = (0..100).fold(Big::from(1), |acc, _| acc.mul(&Big::from(2)));
+        assert_eq!(big.to_string(), "1267650600228229401496703205376");
+        assert_eq!(big.bits(), 101);
+        let (quotient, remainder) = big.add(&Big::from(5)).div_rem(&Big::from(1i64 << 40).mul(&Big::from(-3))).unwrap();
+        assert_eq!((quotient.to_string(), remainder.to_string()), ("-384307168202282325".to_string(), "1099511627781".to_string()));
+    }
+}
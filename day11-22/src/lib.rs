@@ -0,0 +1,450 @@
+pub mod big;
+
+use big::Big;
+use common::{Input, Solution};
+use log::{info, trace};
+use std::collections::VecDeque;
+use std::fmt::Display;
+
+/// A worry level, which may fail to compute when it overflows or divides by 0
+pub trait Worry: Clone + Display + From<i64> {
+  /// Whether the worry levels have to be kept small
+  const BOUNDED: bool;
+
+  fn apply(op: BinOp, left: &Self, right: &Self) -> Option<Self>;
+  fn neg(&self) -> Option<Self>;
+  fn is_zero(&self) -> bool;
+  fn is_negative(&self) -> bool;
+  /// The number of bits of the magnitude
+  fn bits(&self) -> usize;
+}
+
+impl Worry for i64 {
+  const BOUNDED: bool = true;
+
+  fn apply(op: BinOp, left: &i64, right: &i64) -> Option<i64> {
+    match op {
+      BinOp::Plus  => left.checked_add(*right),
+      BinOp::Minus => left.checked_sub(*right),
+      BinOp::Mult  => left.checked_mul(*right),
+      BinOp::Div   => left.checked_div(*right),
+      BinOp::Rem   => left.checked_rem(*right)
+    }
+  }
+  fn neg(&self) -> Option<i64> { self.checked_neg() }
+  fn is_zero(&self) -> bool { *self == 0 }
+  fn is_negative(&self) -> bool { *self < 0 }
+  fn bits(&self) -> usize { (64 - self.unsigned_abs().leading_zeros()) as usize }
+}
+
+impl Worry for Big {
+  const BOUNDED: bool = false;
+
+  fn apply(op: BinOp, left: &Big, right: &Big) -> Option<Big> {
+    match op {
+      BinOp::Plus  => Some(left.add(right)),
+      BinOp::Minus => Some(left.sub(right)),
+      BinOp::Mult  => Some(left.mul(right)),
+      BinOp::Div   => left.div_rem(right).map(|(quotient, _)| quotient),
+      BinOp::Rem   => left.div_rem(right).map(|(_, remainder)| remainder)
+    }
+  }
+  fn neg(&self) -> Option<Big> { Some(Big::neg(self)) }
+  fn is_zero(&self) -> bool { Big::is_zero(self) }
+  fn is_negative(&self) -> bool { Big::is_negative(self) }
+  fn bits(&self) -> usize { Big::bits(self) }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BinOp { Plus, Minus, Mult, Div, Rem }
+
+impl Display for BinOp {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", match &self { BinOp::Plus => "+", BinOp::Minus => "-", BinOp::Mult => "*", BinOp::Div => "/", BinOp::Rem => "%" })
+  }
+}
+
+/// An operation parsed from the front of the tokens, and the tokens after it
+type Parse<'a> = Result<(Op, &'a [String]), String>;
+
+/// The right-hand side of an operation like `new = (old + 3) * old`
+#[derive(Clone, Debug, PartialEq)]
+pub enum Op { Old, Int(i64), Neg(Box<Op>), Binary(BinOp, Box<Op>, Box<Op>) }
+
+impl Op {
+  /// Parse with the usual precedence: `*`, `/` and `%` bind stronger than `+` and `-`, all of them from left to right
+  pub fn new(op_string: &str) -> Result<Op, String> {
+    let tokens = Op::tokenize(op_string)?;
+    let (op, rest) = Op::sum(&tokens)?;
+    match rest.first() {
+      None        => Ok(op),
+      Some(token) => Err(format!("Unexpected `{token}` in `{op_string}`"))
+    }
+  }
+
+  fn tokenize(op_string: &str) -> Result<Vec<String>, String> {
+    let mut tokens: Vec<String> = vec![];
+    let mut chars = op_string.chars().peekable();
+    while let Some(c) = chars.next() {
+      match c {
+        c if c.is_whitespace()                   => (),
+        '+' | '-' | '*' | '/' | '%' | '(' | ')'  => tokens.push(c.to_string()),
+        c if c.is_ascii_alphanumeric()           => {
+          let mut token = c.to_string();
+          while let Some(c) = chars.next_if(|c| c.is_ascii_alphanumeric()) { token.push(c); }
+          tokens.push(token);
+        },
+        c                                        => return Err(format!("Unexpected `{c}` in `{op_string}`"))
+      }
+    }
+    Ok(tokens)
+  }
+
+  fn binary<'a>(tokens: &'a [String], ops: &[(&str, BinOp)], operand: fn(&'a [String]) -> Parse<'a>) -> Parse<'a> {
+    let (mut left, mut rest) = operand(tokens)?;
+    while let Some((_, bin_op)) = rest.first().and_then(|token| ops.iter().find(|(symbol, _)| symbol == token)) {
+      let (right, after) = operand(&rest[1..])?;
+      left = Op::Binary(*bin_op, Box::new(left), Box::new(right));
+      rest = after;
+    }
+    Ok((left, rest))
+  }
+
+  fn sum(tokens: &[String]) -> Parse<'_> {
+    Op::binary(tokens, &[("+", BinOp::Plus), ("-", BinOp::Minus)], Op::product)
+  }
+
+  fn product(tokens: &[String]) -> Parse<'_> {
+    Op::binary(tokens, &[("*", BinOp::Mult), ("/", BinOp::Div), ("%", BinOp::Rem)], Op::factor)
+  }
+
+  fn factor(tokens: &[String]) -> Parse<'_> {
+    match tokens.first().map(|token| token.as_str()) {
+      None         => Err("Operation ends too early".to_string()),
+      Some("old")  => Ok((Op::Old, &tokens[1..])),
+      Some("-")    => Op::factor(&tokens[1..]).map(|(op, rest)| (Op::Neg(Box::new(op)), rest)),
+      Some("(")    => match Op::sum(&tokens[1..])? {
+        (op, [close, rest @ ..]) if close == ")" => Ok((op, rest)),
+        _                                        => Err("Missing `)`".to_string())
+      },
+      Some(token)  => token.parse::<i64>().map(|v| (Op::Int(v), &tokens[1..])).map_err(|_| format!("Unexpected `{token}`"))
+    }
+  }
+
+  /// The new worry level, unless it overflows or divides by 0
+  pub fn apply<W: Worry>(&self, old: &W) -> Option<W> {
+    match &self {
+      Op::Old                       => Some(old.clone()),
+      Op::Int(v)                    => Some(W::from(*v)),
+      Op::Neg(op)                   => op.apply(old)?.neg(),
+      Op::Binary(bin_op, left, right) => W::apply(*bin_op, &left.apply(old)?, &right.apply(old)?)
+    }
+  }
+
+  /// Whether the result modulo m only depends on old modulo m,
+  /// which allows keeping worry levels small
+  pub fn is_modular(&self) -> bool {
+    match &self {
+      Op::Old | Op::Int(_)          => true,
+      Op::Neg(op)                   => op.is_modular(),
+      Op::Binary(bin_op, left, right) => !matches!(bin_op, BinOp::Div | BinOp::Rem) && left.is_modular() && right.is_modular()
+    }
+  }
+}
+
+impl Display for Op {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match &self {
+      Op::Old                       => write!(f, "old"),
+      Op::Int(v)                    => write!(f, "{v}"),
+      Op::Neg(op)                   => write!(f, "-{op}"),
+      Op::Binary(bin_op, left, right) => write!(f, "({left} {bin_op} {right})")
+    }
+  }
+}
+
+#[derive(Clone, Debug)]
+pub struct ThrowTest { pub divisible_by: i64, pub if_true: usize, pub if_false: usize }
+
+impl ThrowTest {
+  pub fn which<W: Worry>(&self, item: &W) -> usize {
+    if W::apply(BinOp::Rem, item, &W::from(self.divisible_by)).is_some_and(|rest| rest.is_zero()) {
+      (&self).if_true 
+    } else {
+      (&self).if_false
+    }
+  }
+}
+
+/// Which monkey could not be parsed and why
+#[derive(Debug)]
+pub struct ParseError { pub monkey: usize, pub reason: String }
+
+impl Display for ParseError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "failed to parse monkey {}: {}", self.monkey, self.reason)
+  }
+}
+
+#[derive(Clone, Debug)]
+pub struct Monkey<W = i64> { pub items: VecDeque<W>, pub operation: Op, pub throw_test: ThrowTest, pub inspected: i64 }
+
+impl Monkey {
+  /// Parse the lines of a monkey as `key: value` pairs, in any order and with any indentation
+  pub fn new(id: usize, monkey_strings: &Vec<&String>) -> Result<Monkey, ParseError> {
+    let error = |reason: String| ParseError { monkey: id, reason };
+    let mut fields = std::collections::HashMap::new();
+    for line in monkey_strings {
+      let (key, value) = line.split_once(':').ok_or_else(|| error(format!("expected `key: value` in `{}`", line.trim())))?;
+      fields.insert(key.split_whitespace().collect::<Vec<&str>>().join(" "), value.trim());
+    }
+    if !fields.contains_key(&format!("Monkey {id}")) {
+      return Err(error(format!("expected a line `Monkey {id}:` in order")));
+    }
+    let field = |key: &str, prefix: &str| -> Result<&str, ParseError> {
+      let value = fields.get(key).ok_or_else(|| error(format!("missing `{key}`")))?;
+      value.strip_prefix(prefix).map(|rest| rest.trim()).ok_or_else(|| error(format!("expected `{key}: {prefix}...`, found `{value}`")))
+    };
+    let number = |key: &str, prefix: &str| -> Result<usize, ParseError> {
+      let value = field(key, prefix)?;
+      value.parse::<usize>().map_err(|_| error(format!("`{value}` in `{key}` is not a number")))
+    };
+
+    let items = field("Starting items", "")?.split(',').map(|item| item.trim()).filter(|item| !item.is_empty()).map(
+      |item| item.parse::<i64>().map_err(|_| error(format!("`{item}` is not a worry level")))
+    ).collect::<Result<VecDeque<i64>, ParseError>>()?;
+    let operation = Op::new(field("Operation", "new =")?).map_err(error)?;
+    let divisible_by = number("Test", "divisible by")? as i64;
+    if divisible_by == 0 { return Err(error("cannot test for divisibility by 0".to_string())); }
+
+    Ok(Monkey {
+      items,
+      operation,
+      throw_test: ThrowTest { divisible_by, if_true: number("If true", "throw to monkey")?, if_false: number("If false", "throw to monkey")? },
+      inspected: 0
+    })
+  }
+
+  /// The same monkey, keeping track of the exact worry levels
+  pub fn exact(self) -> Monkey<Big> {
+    Monkey { items: self.items.into_iter().map(Big::from).collect(), operation: self.operation, throw_test: self.throw_test, inspected: self.inspected }
+  }
+}
+
+impl<W: Worry> Monkey<W> {
+  pub fn throw(&self, item: &W, throw_to: &mut Vec<VecDeque<W>>, modulo: Option<i64>, relieve: i64) {
+    let overflow = || panic!("Worry level {item} overflows or divides by 0 in {}", (&self).operation);
+    let worry    = (&self).operation.apply(item).unwrap_or_else(overflow);
+    let relieved = W::apply(BinOp::Div, &worry, &W::from(relieve)).unwrap_or_else(overflow);
+    let new_item = match modulo {
+      Some(modulo) => {
+        let rest = W::apply(BinOp::Rem, &relieved, &W::from(modulo)).unwrap_or_else(overflow);
+        if rest.is_negative() { W::apply(BinOp::Plus, &rest, &W::from(modulo)).unwrap_or_else(overflow) } else { rest }
+      },
+      None         => relieved
+    };
+    throw_to[(&self).throw_test.which(&new_item)].push_back(new_item);
+  }
+}
+
+impl<W: Worry> Display for Monkey<W> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", &self.items.iter().enumerate().fold(
+      "".to_string(),
+      |acc, (i,item)| {
+        format!("{acc}{}{}", if i == 0 {""} else {", "}, item)
+      } 
+    ))
+  }
+}
+
+pub fn prepare(lines: &[String]) -> Vec<Monkey> {
+  let monkeys = lines.iter().fold(
+    vec![vec![]],
+    |mut acc, line| {
+      match line.trim().len() {
+        0 => { acc.push(vec![]); acc },
+        _ => { acc.last_mut().unwrap().push(line); acc}
+      }
+    }
+  ).iter().filter(|monkey_strings| !monkey_strings.is_empty()).enumerate().map(|(id, monkey_strings)| {
+    Monkey::new(id, monkey_strings)
+  }).collect::<Result<Vec<Monkey>, ParseError>>().unwrap_or_else(|err| panic!("{err}"));
+
+  // Check the throw targets now, instead of in the middle of a round
+  monkeys.iter().enumerate().for_each(
+    |(id, monkey)| {
+      if let Some(target) = [monkey.throw_test.if_true, monkey.throw_test.if_false].into_iter().find(|target| *target >= monkeys.len()) {
+        panic!("{}", ParseError { monkey: id, reason: format!("throws to monkey {target}, but there are only {} monkeys", monkeys.len()) });
+      }
+    }
+  );
+
+  return monkeys;
+}
+
+/// The most bits an exact worry level may have, as every squaring doubles them and slows down the next ones
+pub const MAX_EXACT_BITS: usize = 1 << 16;
+
+fn gcd(a: i64, b: i64) -> i64 { if b == 0 { a } else { gcd(b, a % b) } }
+
+/// The least common multiple of the positive numbers, unless it overflows
+pub fn lcm(numbers: impl Iterator<Item = i64>) -> Option<i64> {
+  numbers.into_iter().try_fold(1, |lcm: i64, n| (lcm / gcd(lcm, n)).checked_mul(n))
+}
+
+/// Play the rounds, logging the items after every round if `trace` is set.
+/// Bounded worry levels are kept modulo the least common multiple of all divisors unless they are divided,
+/// which does not change any test, as long as the operations allow it.
+pub fn play<W: Worry>(mut monkeys: Vec<Monkey<W>>, rounds: i64, relieve: i64, trace: bool) -> Vec<Monkey<W>> {
+  let modular = W::BOUNDED && relieve == 1 && monkeys.iter().all(|monkey| monkey.operation.is_modular());
+  let modulo  = if modular {
+    let divisors = monkeys.iter().map(|monkey| monkey.throw_test.divisible_by);
+    Some(lcm(divisors).unwrap_or_else(|| panic!("The least common multiple of the divisors overflows, so the worry levels cannot be kept small: try --exact")))
+  } else {
+    None
+  };
+  (1..=rounds).for_each(|round| {
+    for i in 0..monkeys.len() {
+      let mut throws: Vec<VecDeque<W>> = (0..monkeys.len()).map(|_| VecDeque::new()).collect();
+      monkeys[i].items.iter().for_each(|item| {
+        monkeys[i].throw(item, &mut throws, modulo, relieve);
+      });
+      monkeys[i].inspected += monkeys[i].items.len() as i64;
+      monkeys[i].items.clear();
+      throws.iter_mut().enumerate().for_each(|(j,items)| monkeys[j].items.append(items));
+    }
+    if !W::BOUNDED {
+      if let Some(bits) = monkeys.iter().flat_map(|monkey| monkey.items.iter().map(W::bits)).max().filter(|bits| *bits > MAX_EXACT_BITS) {
+        panic!("After round {round}, an exact worry level has {bits} bits, more than {MAX_EXACT_BITS}: play fewer rounds with --rounds");
+      }
+    }
+    if trace {
+      info!("After round {round}, the monkeys are holding items with these worry levels:{}", monkeys.iter().enumerate().fold(
+        "".to_string(),
+        |acc, (i, monkey)| {
+          format!("{acc}\nMonkey {i}: {monkey}")
+        }));
+    }
+  });
+  monkeys
+}
+
+/// The product of the two highest numbers of inspected items after the rounds, which `--rounds` overrides.
+/// With `--exact` the worry levels are not reduced, so they are the real ones in the trace of `--trace`,
+/// but they grow fast without relief: squaring them doubles their size every time, so `play` stops at `MAX_EXACT_BITS`.
+pub fn monkey_business(monkeys: &Vec<Monkey>, input: &Input, rounds: i64, relieve: i64) -> String {
+  let monkeys = monkeys.clone();
+  let rounds  = input.option("rounds").unwrap_or(rounds);
+  let trace   = input.flag("trace");
+  let monkeys: Vec<i64> = if input.flag("exact") {
+    play(monkeys.into_iter().map(Monkey::exact).collect(), rounds, relieve, trace).into_iter().map(|monkey| monkey.inspected).collect()
+  } else {
+    play(monkeys, rounds, relieve, trace).into_iter().map(|monkey| monkey.inspected).collect()
+  };
+
+  trace!("{}", monkeys.iter().enumerate().fold(
+    "".to_string(),
+    |acc, (i, inspected)| {
+      format!("{acc}\nMonkey {i} inspected items {inspected} times")
+    }
+  ));
+
+  let mut monkey_business = monkeys.into_iter().enumerate().collect::<Vec<(usize,i64)>>();
+  monkey_business.sort_by(|(_,x), (_,y)| y.cmp(x));
+
+  return (monkey_business[0].1 * monkey_business[1].1).to_string();
+}
+
+pub fn one(monkeys: &Vec<Monkey>, input: &Input) -> String {
+  monkey_business(monkeys, input, 20, 3)
+}
+
+pub fn two(monkeys: &Vec<Monkey>, input: &Input) -> String {
+  monkey_business(monkeys, input, 10000, 1)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+  type Parsed = Vec<Monkey>;
+
+  fn prepare(lines: &[String]) -> Vec<Monkey> { prepare(lines) }
+  fn one(parsed: &Vec<Monkey>, input: &Input) -> String { one(parsed, input) }
+  fn two(parsed: &Vec<Monkey>, input: &Input) -> String { two(parsed, input) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn precedence_and_parentheses() {
+        assert_eq!(Op::new("old + 2 * old").unwrap().to_string(), "(old + (2 * old))");
+        assert_eq!(Op::new("old - 1 - old % 4").unwrap().to_string(), "((old - 1) - (old % 4))");
+        assert_eq!(Op::new("(old + 2) * old").unwrap().to_string(), "((old + 2) * old)");
+        assert_eq!(Op::new("(old + 2) * old").unwrap().apply(&3i64), Some(15));
+    }
+
+    #[test]
+    fn unary_minus() {
+        let op = Op::new("-old * -(2 - old)").unwrap();
+        assert_eq!(op, Op::Binary(BinOp::Mult,
+            Box::new(Op::Neg(Box::new(Op::Old))),
+            Box::new(Op::Neg(Box::new(Op::Binary(BinOp::Minus, Box::new(Op::Int(2)), Box::new(Op::Old)))))));
+        assert_eq!(op.apply(&5i64), Some(-15));
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(Op::new("old old"), Err("Unexpected `old` in `old old`".to_string()));
+        assert_eq!(Op::new("old + )"), Err("Unexpected `)`".to_string()));
+        assert_eq!(Op::new("old & 2"), Err("Unexpected `&` in `old & 2`".to_string()));
+        assert_eq!(Op::new("(old + 1"), Err("Missing `)`".to_string()));
+        assert_eq!(Op::new("old *"), Err("Operation ends too early".to_string()));
+    }
+
+    #[test]
+    #[should_panic(expected = "failed to parse monkey 0: throws to monkey 2, but there are only 1 monkeys")]
+    fn throw_target_out_of_range() {
+        let lines = ["Monkey 0:", "  Starting items: 79", "  Operation: new = old * 19", "  Test: divisible by 23",
+                     "    If true: throw to monkey 2", "    If false: throw to monkey 0"];
+        prepare(&lines.map(|line| line.to_string()));
+    }
+
+    #[test]
+    #[should_panic(expected = "After round 15, an exact worry level has 91992 bits, more than 65536")]
+    fn exact_levels_are_capped() {
+        let lines = ["Monkey 0:", "  Starting items: 7", "  Operation: new = old * old", "  Test: divisible by 2",
+                     "    If true: throw to monkey 0", "    If false: throw to monkey 0"];
+        let monkeys = prepare(&lines.map(|line| line.to_string()));
+        play(monkeys.into_iter().map(Monkey::exact).collect(), 100, 1, false);
+    }
+
+    /// Monkeys that each throw to the next one if the test passes, else to the one after, testing for the given divisors
+    fn ring(divisors: &[i64]) -> Vec<Monkey> {
+        let lines: Vec<String> = divisors.iter().enumerate().flat_map(|(id, divisor)| {
+            let (next, after) = ((id + 1) % divisors.len(), (id + 2) % divisors.len());
+            [format!("Monkey {id}:"), format!("  Starting items: {}", id + 1), "  Operation: new = old * 3 + 1".to_string(),
+             format!("  Test: divisible by {divisor}"), format!("    If true: throw to monkey {next}"),
+             format!("    If false: throw to monkey {after}"), String::new()]
+        }).collect();
+        prepare(&lines)
+    }
+
+    #[test]
+    fn divisors_combine_by_lcm() {
+        assert_eq!(lcm([4, 6, 10].into_iter()), Some(60));
+        // The product of the divisors overflows, but they share all their factors
+        let divisors = [1_000_003, 7, 2_000_006, 1_000_003, 14].repeat(6);
+        let bounded  = play(ring(&divisors), 100, 1, false);
+        let exact    = play(ring(&divisors).into_iter().map(Monkey::exact).collect(), 100, 1, false);
+        assert_eq!(bounded.iter().map(|monkey| monkey.inspected).collect::<Vec<i64>>(), exact.iter().map(|monkey| monkey.inspected).collect::<Vec<i64>>());
+    }
+
+    #[test]
+    #[should_panic(expected = "The least common multiple of the divisors overflows")]
+    fn divisors_overflow() {
+        play(ring(&[2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61]), 1, 1, false);
+    }
+}
@@ -0,0 +1,299 @@
+use common::{Input, Solution};
+use log::info;
+use std::collections::HashMap;
+use priority_queue::DoublePriorityQueue;
+
+pub type Height = usize;
+
+pub fn to_height(c: char) -> Height {
+  match c {
+    'S'                                           => to_height('a'),
+    'E'                                           => to_height('z'),
+     c if 97 <= c as Height && c as Height <= 122 => c as Height-97,
+     _                                            => panic!("Malformed height character: {c}")
+  }
+}
+
+pub struct HeightMap { pub map: Vec<Vec<Height>>, pub width: usize, pub height: usize, pub start: (usize,usize), pub end: (usize,usize) }
+
+impl HeightMap {
+  pub fn new(lines: &[String]) -> HeightMap {
+    let mut width  = 0;
+    let     height = lines.len();
+    let mut start: Option<Node> = None;
+    let mut end  : Option<Node> = None;
+    let map = lines.iter().enumerate().map(
+      |(y,line)| {
+        let row: Vec<Height> = line.chars().enumerate().map(
+          |(x,c)| {
+            match c { 
+              'S' => start = Some((x,y)),
+              'E' => end   = Some((x,y)),
+               _  => ()
+            }
+            to_height(c)
+          }
+        ).collect();
+        width = std::cmp::max(width,row.len());
+        row
+      }
+    ).collect();
+    if start.is_none() { panic!("No start specified") }
+    if end.is_none()   { panic!("No end specified") }
+    HeightMap { map: map, width: width, height: height, start: start.unwrap(), end: end.unwrap() }
+  }
+
+  pub fn height_at(&self, (x,y): &(usize,usize)) -> Height { (&self).map[*y][*x] }
+}
+
+pub type Node = (usize,usize);
+
+/// How one may move over the height map and what it costs
+pub trait Rules {
+  /// Whether one may step from the current height to the next one: by default at most one up, but any amount down
+  fn climbable(&self, curr_height: Height, next_height: Height) -> bool { next_height <= curr_height+1 }
+
+  /// Whether one may also step diagonally
+  fn diagonal(&self) -> bool { false }
+
+  /// The cost of a step, which has to be at least 1
+  fn cost(&self, _curr_height: Height, _next_height: Height) -> usize { 1 }
+
+  /// A lower bound of the cost from here to there, since every step costs at least 1 and covers at most one row and one column
+  fn estimate(&self, (x1,y1): &Node, (x2,y2): &Node) -> usize {
+    if self.diagonal() { x1.abs_diff(*x2).max(y1.abs_diff(*y2)) } else { x1.abs_diff(*x2) + y1.abs_diff(*y2) }
+  }
+}
+
+/// The rules of the puzzle, with the maximal climb, diagonal steps and an extra cost per unit of height climbed as options
+#[derive(Clone, Debug)]
+pub struct Climbing { pub max_climb: Height, pub diagonal: bool, pub climb_weight: usize }
+
+impl Climbing {
+  pub const STANDARD: Climbing = Climbing { max_climb: 1, diagonal: false, climb_weight: 0 };
+
+  /// The rules changed by `--max-climb N`, `--diagonal` and `--climb-weight N`
+  pub fn new(input: &Input) -> Climbing {
+    Climbing {
+      max_climb   : input.option("max-climb").unwrap_or(Climbing::STANDARD.max_climb),
+      diagonal    : input.flag("diagonal"),
+      climb_weight: input.option("climb-weight").unwrap_or(Climbing::STANDARD.climb_weight)
+    }
+  }
+}
+
+impl Rules for Climbing {
+  fn climbable(&self, curr_height: Height, next_height: Height) -> bool { next_height <= curr_height+self.max_climb }
+  fn diagonal(&self) -> bool { self.diagonal }
+  fn cost(&self, curr_height: Height, next_height: Height) -> usize { 1 + self.climb_weight*next_height.saturating_sub(curr_height) }
+}
+
+pub fn adjacent(node: Node, width: usize, height: usize, diagonal: bool) -> Vec<Node> {
+  let straight = [(1,0),(0,1),(-1,0),(0,-1)];
+  let diagonals = if diagonal { vec![(1,1),(-1,1),(-1,-1),(1,-1)] } else { vec![] };
+  straight.into_iter().chain(diagonals).map(
+    |(dx,dy): (i32,i32)| {
+      let (nx,ny) = (node.0 as i32+dx, node.1 as i32+dy);
+      if 0 <= nx && nx < width as i32 && 0 <= ny && ny < height as i32 { 
+        Some((nx as usize, ny as usize))
+      } else {
+        None
+      }
+    }
+  ).filter_map(|node_option| node_option).collect()
+}
+
+/// The cost of stepping from one height to another, if the rules allow it
+fn step<R: Rules>(rules: &R, curr_height: Height, next_height: Height) -> Option<usize> {
+  if !rules.climbable(curr_height, next_height) { return None; }
+  let cost = rules.cost(curr_height, next_height);
+  if cost == 0 { panic!("A step from height {curr_height} to {next_height} costs 0, but every step has to cost at least 1"); }
+  Some(cost)
+}
+
+/// The cheapest path from the start to the first goal taken from the queue, where `cost` gives the cost of the step 
+/// between two squares if it is allowed. The queue is ordered by the cost so far plus `estimate`, which must never 
+/// overestimate the cost left, so the path found is the cheapest one.
+pub fn search(height_map: &HeightMap, start: Node, diagonal: bool, is_goal: impl Fn(&Node) -> bool,
+              cost: impl Fn(&Node, &Node) -> Option<usize>, estimate: impl Fn(&Node) -> usize) -> Option<Vec<Node>> {
+  let mut unvisited = DoublePriorityQueue::new();
+  unvisited.push(start, estimate(&start));
+
+  // The cheapest cost found so far for every square, and where it came from
+  let mut costs: HashMap<Node, (usize, Option<Node>)> = HashMap::from([(start, (0, None))]);
+
+  while let Some((curr, _)) = unvisited.pop_min() {
+    if is_goal(&curr) {
+      let mut path = vec![curr];
+      while let Some((_, Some(prev))) = costs.get(path.last().unwrap()) { path.push(*prev); }
+      path.reverse();
+      return Some(path);
+    }
+    let curr_cost = costs[&curr].0;
+    adjacent(curr, height_map.width, height_map.height, diagonal).into_iter().for_each(
+      |next| {
+        if let Some(step_cost) = cost(&curr, &next) {
+          let next_cost = curr_cost+step_cost;
+          if costs.get(&next).is_none_or(|(known, _)| next_cost < *known) {
+            costs.insert(next, (next_cost, Some(curr)));
+            unvisited.push_decrease(next, next_cost+estimate(&next));
+          }
+        }
+      }
+    );
+  }
+
+  return None;
+}
+
+/// The cheapest path from the start to the end
+pub fn astar<R: Rules>(height_map: &HeightMap, rules: &R, start: Node, end: Node) -> Option<Vec<Node>> {
+  search(
+    height_map, start, rules.diagonal(),
+    |node| *node == end,
+    |curr, next| step(rules, height_map.height_at(curr), height_map.height_at(next)),
+    |node| rules.estimate(node, &end)
+  )
+}
+
+/// The cheapest path from any lowest square to the end, found by walking down from the end with the steps reversed,
+/// so the first lowest square taken from the queue is the closest one
+pub fn descend<R: Rules>(height_map: &HeightMap, rules: &R) -> Option<Vec<Node>> {
+  search(
+    height_map, height_map.end, rules.diagonal(),
+    |node| height_map.height_at(node) == 0,
+    |curr, next| step(rules, height_map.height_at(next), height_map.height_at(curr)),
+    |_| 0
+  ).map(|path| path.into_iter().rev().collect())
+}
+
+/// The path over the height map, with an arrow on every square pointing to the next one, like
+/// ```text
+/// v..v<<<<
+/// >v.vv<<^
+/// .>vv>E^^
+/// ..v>>>^^
+/// ..>>>>>^
+/// ```
+pub fn render(height_map: &HeightMap, path: &[Node]) -> String {
+  let mut picture = vec![vec!['.'; height_map.width]; height_map.height];
+  path.windows(2).for_each(
+    |step| {
+      let ((x1,y1), (x2,y2)) = (step[0], step[1]);
+      picture[y1][x1] = match (x2 as i32 - x1 as i32, y2 as i32 - y1 as i32) {
+        (1,0)   => '>',
+        (-1,0)  => '<',
+        (0,1)   => 'v',
+        (0,-1)  => '^',
+        (1,1)   => '↘',
+        (-1,1)  => '↙',
+        (1,-1)  => '↗',
+        _       => '↖'
+      };
+    }
+  );
+  if let Some((x,y)) = path.last() { picture[*y][*x] = 'E'; }
+
+  return picture.into_iter().map(|row| row.into_iter().collect::<String>()).collect::<Vec<String>>().join("\n");
+}
+
+/// The cost of the path, which is drawn if `--render` is given
+pub fn total_cost<R: Rules>(input: &Input, height_map: &HeightMap, rules: &R, path: Option<Vec<Node>>) -> String {
+  match path {
+    Some(path) => {
+      if input.flag("render") { info!("Path:\n{}", render(height_map, &path)); }
+      path.windows(2).map(|step| rules.cost(height_map.height_at(&step[0]), height_map.height_at(&step[1]))).sum::<usize>().to_string()
+    },
+    None       => "Unreachable".to_string()
+  }
+}
+
+pub fn one(height_map: &HeightMap, input: &Input) -> String {
+  let rules        = Climbing::new(input);
+  let (start, end) = (height_map.start, height_map.end);
+  return total_cost(input, height_map, &rules, astar(height_map, &rules, start, end));
+}
+
+pub fn two(height_map: &HeightMap, input: &Input) -> String {
+  let rules = Climbing::new(input);
+  return total_cost(input, height_map, &rules, descend(height_map, &rules));
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+  type Parsed = HeightMap;
+
+  fn prepare(lines: &[String]) -> HeightMap { HeightMap::new(lines) }
+  fn one(parsed: &HeightMap, input: &Input) -> String { one(parsed, input) }
+  fn two(parsed: &HeightMap, input: &Input) -> String { two(parsed, input) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::VecDeque;
+
+    /// The fewest steps from the start to the end, by a plain breadth first search
+    fn bfs<R: Rules>(height_map: &HeightMap, rules: &R, start: Node, end: Node) -> Option<usize> {
+        let mut queue = VecDeque::from([(start, 0)]);
+        let mut seen  = HashMap::from([(start, ())]);
+        while let Some((curr, steps)) = queue.pop_front() {
+            if curr == end { return Some(steps); }
+            for next in adjacent(curr, height_map.width, height_map.height, rules.diagonal()) {
+                if !seen.contains_key(&next) && rules.climbable(height_map.height_at(&curr), height_map.height_at(&next)) {
+                    seen.insert(next, ());
+                    queue.push_back((next, steps+1));
+                }
+            }
+        }
+        None
+    }
+
+    /// Height maps of random letters, from a fixed seed
+    fn height_maps() -> Vec<HeightMap> {
+        let mut seed: u64 = 12;
+        let mut random = move |n: u64| { seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407); (seed >> 33) % n };
+        (0..50).map(
+            |_| {
+                let (width, height) = (2 + random(12) as usize, 2 + random(8) as usize);
+                let mut lines: Vec<Vec<char>> = (0..height).map(|_| (0..width).map(|_| (b'a' + random(6) as u8) as char).collect()).collect();
+                lines[random(height as u64) as usize][random(width as u64) as usize] = 'S';
+                lines[0][0] = 'E';
+                HeightMap::new(&lines.into_iter().map(|line| line.into_iter().collect()).collect::<Vec<String>>())
+            }
+        ).collect()
+    }
+
+    #[test]
+    fn astar_matches_bfs() {
+        let rules = [
+            Climbing::STANDARD,
+            Climbing { max_climb: 0, diagonal: false, climb_weight: 0 },
+            Climbing { max_climb: 2, diagonal: true, climb_weight: 0 }
+        ];
+        let mut reached = 0;
+        for height_map in height_maps() {
+            for rules in &rules {
+                // The end of the map is too high, so search to every square instead
+                for end in (0..height_map.height).flat_map(|y| (0..height_map.width).map(move |x| (x,y))) {
+                    let steps = astar(&height_map, rules, height_map.start, end).map(|path| path.len()-1);
+                    assert_eq!(steps, bfs(&height_map, rules, height_map.start, end));
+                    if steps.is_some() { reached += 1; }
+                }
+            }
+        }
+        assert!(reached > 1000);
+    }
+
+    #[test]
+    fn weighted_climbing_avoids_bumps() {
+        let height_map = HeightMap::new(&vec!["SzaE".to_string(), "aaaa".to_string()]);
+        let cost       = |rules: &Climbing, path: &[Node]| path.windows(2).map(|step| rules.cost(height_map.height_at(&step[0]), height_map.height_at(&step[1]))).sum::<usize>();
+        let over       = Climbing { max_climb: 25, diagonal: false, climb_weight: 0 };
+        let around     = Climbing { max_climb: 25, diagonal: false, climb_weight: 1 };
+        assert_eq!(cost(&over, &astar(&height_map, &over, height_map.start, height_map.end).unwrap()), 3);
+        assert_eq!(cost(&around, &astar(&height_map, &around, height_map.start, height_map.end).unwrap()), 5 + 25);
+        assert_eq!(cost(&around, &descend(&height_map, &around).unwrap()), 1 + 25);
+    }
+}
@@ -0,0 +1,84 @@
+//! Converts packets to and from JSON, whose arrays of integers look just like them
+
+use crate::Tree;
+use serde_json::Value;
+use std::fmt::Display;
+
+/// Why a JSON value is not a packet, at which position of the packets
+#[derive(Debug, PartialEq)]
+pub struct JsonError { pub packet: Option<usize>, pub reason: String }
+
+impl Display for JsonError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self.packet {
+      Some(i) => write!(f, "Packet {i}: {}", self.reason),
+      None    => write!(f, "{}", self.reason)
+    }
+  }
+}
+
+impl std::error::Error for JsonError {}
+
+impl From<&Tree> for Value {
+  /// An empty tree is `null`
+  fn from(tree: &Tree) -> Value {
+    match tree {
+      Tree::Empty          => Value::Null,
+      Tree::Leaf(v)        => Value::from(*v),
+      Tree::Node(children) => Value::Array(children.iter().map(Value::from).collect())
+    }
+  }
+}
+
+impl TryFrom<&Value> for Tree {
+  type Error = JsonError;
+
+  fn try_from(value: &Value) -> Result<Tree, JsonError> {
+    let error = |reason: String| JsonError { packet: None, reason };
+    match value {
+      Value::Null            => Ok(Tree::Empty),
+      Value::Number(number)  => number.as_i64().and_then(|v| i32::try_from(v).ok()).map(Tree::Leaf).ok_or_else(
+        || error(format!("`{number}` is not a 32-bit integer"))
+      ),
+      Value::Array(children) => children.iter().map(Tree::try_from).collect::<Result<Vec<Tree>, JsonError>>().map(Tree::Node),
+      value                  => Err(error(format!("`{value}` is neither an integer nor an array")))
+    }
+  }
+}
+
+/// The packets of a JSON array
+pub fn read(text: &str) -> Result<Vec<Tree>, JsonError> {
+  let value: Value = serde_json::from_str(text).map_err(|err| JsonError { packet: None, reason: err.to_string() })?;
+  match value {
+    Value::Array(packets) => packets.iter().enumerate().map(
+      |(i, packet)| Tree::try_from(packet).map_err(|err| JsonError { packet: Some(i), ..err })
+    ).collect(),
+    value                 => Err(JsonError { packet: None, reason: format!("Expected an array of packets, found `{value}`") })
+  }
+}
+
+/// The packets as a JSON array with one packet per line
+pub fn write(packets: &[Tree]) -> String {
+  let lines: Vec<String> = packets.iter().map(|packet| format!("  {}", Value::from(packet))).collect();
+  return format!("[\n{}\n]\n", lines.join(",\n"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let packets: Vec<Tree> = ["[1,[2,[-3]],[]]", "[[]]", "[10]"].iter().map(|line| line.parse().unwrap()).collect();
+        let json = write(&packets);
+        assert_eq!(json, "[\n  [1,[2,[-3]],[]],\n  [[]],\n  [10]\n]\n");
+        assert_eq!(read(&json), Ok(packets));
+    }
+
+    #[test]
+    fn reject_other_values() {
+        assert_eq!(read("[[1], [2.5]]").unwrap_err().packet, Some(1));
+        assert_eq!(read("[[\"a\"]]").unwrap_err().to_string(), "Packet 0: `\"a\"` is neither an integer nor an array");
+        assert_eq!(read("{}").unwrap_err().packet, None);
+    }
+}
@@ -0,0 +1,322 @@
+pub mod json;
+
+use common::{Input, Solution};
+use log::{info, trace};
+use std::fs;
+use std::cmp::Ordering;
+use std::fmt::Display;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Token { BOpen, BClose, Comma, Num(i32) }
+
+/// What is wrong with a packet and at which column, counted from 1
+#[derive(Debug, PartialEq)]
+pub struct ParseError { pub column: usize, pub reason: String }
+
+impl Display for ParseError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "Column {}: {}", self.column, self.reason)
+  }
+}
+
+impl std::error::Error for ParseError {}
+
+impl Token {
+  /// The tokens with the columns they start at, skipping whitespace
+  pub fn tokenize(line: &str) -> Result<Vec<(usize, Token)>, ParseError> {
+    let mut rest = line.char_indices().peekable();
+    let mut result = vec![];
+    while let Some((i, c)) = rest.next() {
+      let column = i+1;
+      result.push((column, match c {
+        '['                                => Token::BOpen,
+        ']'                                => Token::BClose,
+        ','                                => Token::Comma,
+        c if c.is_whitespace()             => continue,
+        c if c == '-' || c.is_ascii_digit() => {
+          let mut number = c.to_string();
+          while let Some((_, digit)) = rest.next_if(|(_, c)| c.is_ascii_digit()) { number.push(digit); }
+          Token::Num(number.parse().map_err(|_| ParseError { column, reason: format!("`{number}` is not a number") })?)
+        },
+        c                                  => return Err(ParseError { column, reason: format!("Unexpected `{c}`") })
+      }));
+    }
+    return Ok(result);
+  }
+}
+
+/// A packet or a part of it. Trees are equal if they have the same structure,
+/// while `packet_cmp` and `Packet` order them like packets.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Tree { Empty, Leaf(i32), Node(Vec<Tree>) }
+
+impl Tree {
+  /// Parse a packet, or panic if it is malformed
+  pub fn parse_tree(line: &String) -> Tree {
+    line.parse().unwrap_or_else(|err| panic!("Malformed packet: {err}\n{line}"))
+  }
+
+  /// Parse a number or a list from the tokens starting at `i`, returning the index after it
+  fn parse_tokens(tokens: &[(usize, Token)], i: usize) -> Result<(Tree, usize), ParseError> {
+    let error = |column: usize, reason: &str| ParseError { column, reason: reason.to_string() };
+    let (open, token) = &tokens[i];
+    match token {
+      Token::Num(v)  => Ok((Tree::Leaf(*v), i+1)),
+      Token::BClose  => Err(error(*open, "`]` without a matching `[`")),
+      Token::Comma   => Err(error(*open, "Expected a number or `[`, found `,`")),
+      Token::BOpen   => {
+        let mut children = vec![];
+        let mut i = i+1;
+        if let Some((_, Token::BClose)) = tokens.get(i) { return Ok((Tree::Node(children), i+1)); }
+        loop {
+          match tokens.get(i) {
+            None                          => return Err(error(*open, "`[` is never closed")),
+            Some((column, Token::BClose)) => return Err(error(*column, "Expected a number or `[` after `,`")),
+            _                             => ()
+          }
+          let (child, next) = Tree::parse_tokens(tokens, i)?;
+          children.push(child);
+          match tokens.get(next) {
+            Some((_, Token::Comma))       => i = next+1,
+            Some((_, Token::BClose))      => return Ok((Tree::Node(children), next+1)),
+            Some((column, _))             => return Err(error(*column, "Expected `,` or `]`")),
+            None                          => return Err(error(*open, "`[` is never closed"))
+          }
+        }
+      }
+    }
+  }
+}
+
+impl std::str::FromStr for Tree {
+  type Err = ParseError;
+
+  /// Parse a packet like `[1,[2,-3],[]]`, or an empty tree from an empty line
+  fn from_str(line: &str) -> Result<Tree, ParseError> {
+    let tokens = Token::tokenize(line)?;
+    if tokens.is_empty() { return Ok(Tree::Empty); }
+    let (tree, next) = Tree::parse_tokens(&tokens, 0)?;
+    return match tokens.get(next) {
+      None              => Ok(tree),
+      Some((column, _)) => Err(ParseError { column: *column, reason: "Unexpected token after the packet".to_string() })
+    };
+  }
+}
+
+impl Tree {
+  /// The path of indices to where the packets first differ, and how they compare there, unless they are equal.
+  /// The path may go into a number compared with a list, or end just past the last item of the list that ran out.
+  pub fn difference(&self, other: &Tree) -> Option<(Vec<usize>, Ordering)> {
+    fn lists(left: &[Tree], right: &[Tree], path: &mut Vec<usize>) -> Option<Ordering> {
+      for (i, (l, r)) in left.iter().zip(right.iter()).enumerate() {
+        path.push(i);
+        if let Some(ordering) = trees(l, r, path) { return Some(ordering); }
+        path.pop();
+      }
+      let ordering = left.len().cmp(&right.len());
+      if ordering.is_ne() { path.push(left.len().min(right.len())); }
+      Some(ordering).filter(|ordering| ordering.is_ne())
+    }
+
+    fn trees(left: &Tree, right: &Tree, path: &mut Vec<usize>) -> Option<Ordering> {
+      match (left, right) {
+        (Tree::Node(cl), Tree::Node(cr)) => lists(cl, cr, path),
+        (Tree::Leaf(_), Tree::Node(cr))  => lists(std::slice::from_ref(left), cr, path),
+        (Tree::Node(cl), Tree::Leaf(_))  => lists(cl, std::slice::from_ref(right), path),
+        _                                => Some(left.packet_cmp(right)).filter(|ordering| ordering.is_ne())
+      }
+    }
+
+    let mut path = vec![];
+    return trees(self, other, &mut path).map(|ordering| (path, ordering));
+  }
+
+  /// The part at the path, where a number is also the only item of a list, unless the path goes past the end of a list
+  pub fn get(&self, path: &[usize]) -> Option<&Tree> {
+    match (self, path) {
+      (_, [])                                => Some(self),
+      (Tree::Node(children), [i, rest @ ..]) => children.get(*i)?.get(rest),
+      (Tree::Leaf(_), [0, rest @ ..])        => self.get(rest),
+      _                                      => None
+    }
+  }
+
+  /// The start and length of the part at the path in the displayed tree, where a number stands for all its paths
+  /// and the path just past the last item of a list for its `]`
+  pub fn locate(&self, path: &[usize]) -> (usize, usize) {
+    match (self, path) {
+      (Tree::Node(children), [i, rest @ ..]) if *i < children.len() => {
+        let start = 1 + children[..*i].iter().map(|child| child.to_string().len() + 1).sum::<usize>();
+        let (offset, length) = children[*i].locate(rest);
+        (start + offset, length)
+      },
+      (Tree::Node(_), [_, ..])                                      => (self.to_string().len() - 1, 1),
+      _                                                             => (0, self.to_string().len())
+    }
+  }
+}
+
+/// Both packets with the part where they first differ marked below, and why they are in the right order or not
+pub fn explain(left: &Tree, right: &Tree) -> String {
+  let marked = |label: &str, tree: &Tree, path: &[usize]| {
+    let (start, length) = tree.locate(path);
+    format!("{label}: {tree}\n{}{}", " ".repeat(label.len() + 2 + start), "^".repeat(length))
+  };
+
+  match left.difference(right) {
+    None                   => format!("left : {left}\nright: {right}\nThe packets are equal"),
+    Some((path, ordering)) => {
+      let reason = match (left.get(&path), right.get(&path)) {
+        (None, _)          => "the left list runs out of items first".to_string(),
+        (_, None)          => "the right list runs out of items first".to_string(),
+        (Some(l), Some(r)) => format!("{l} is {} than {r}", if ordering == Ordering::Less { "smaller" } else { "larger" })
+      };
+      let order  = if ordering == Ordering::Less { "in the right order" } else { "not in the right order" };
+      let at     = path.iter().map(|i| format!("[{i}]")).collect::<String>();
+      format!("{}\n{}\nAt {at}, {reason}, so the packets are {order}", marked("left ", left, &path), marked("right", right, &path))
+    }
+  }
+}
+
+impl Tree {
+  /// Order the trees like packets, where a number compared with a list is compared as a list of that number,
+  /// so e.g. `[1]` and `1` are in the same place although they are not equal
+  pub fn packet_cmp(&self, other: &Tree) -> Ordering {
+    fn lists(left: &[Tree], right: &[Tree]) -> Ordering {
+      left.iter().zip(right.iter()).map(|(l, r)| l.packet_cmp(r)).find(|ordering| ordering.is_ne()).unwrap_or(left.len().cmp(&right.len()))
+    }
+
+    match (self, other) {
+      (Tree::Empty, Tree::Empty)       => Ordering::Equal,
+      (Tree::Empty, _)                 => Ordering::Less,
+      (_, Tree::Empty)                 => Ordering::Greater,
+      (Tree::Leaf(vl), Tree::Leaf(vr)) => vl.cmp(vr),
+      (Tree::Node(cl), Tree::Node(cr)) => lists(cl, cr),
+      (Tree::Leaf(_), Tree::Node(cr))  => lists(std::slice::from_ref(self), cr),
+      (Tree::Node(cl), Tree::Leaf(_))  => lists(cl, std::slice::from_ref(other))
+    }
+  }
+}
+
+/// A tree that is ordered and compared like a packet, so e.g. `Packet([1])` and `Packet(1)` are equal
+#[derive(Debug, Clone)]
+pub struct Packet(pub Tree);
+
+impl Ord for Packet {
+  fn cmp(&self, other: &Packet) -> Ordering { self.0.packet_cmp(&other.0) }
+}
+
+impl PartialOrd for Packet {
+  fn partial_cmp(&self, other: &Packet) -> Option<Ordering> { Some(self.cmp(other)) }
+}
+
+impl PartialEq for Packet {
+  fn eq(&self, other: &Packet) -> bool { self.cmp(other) == Ordering::Equal }
+}
+
+impl Eq for Packet {}
+
+impl Display for Tree {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match &self {
+      Tree::Empty          => write!(f, "Empty Tree"),
+      Tree::Leaf(v)        => write!(f, "{v}"),
+      Tree::Node(children) => {
+        write!(f, "[{}]", { 
+          let list = children.iter().fold("".to_string(), |acc, child| format!("{acc},{child}"));
+          let mut iter = list.chars(); 
+          iter.next(); 
+          iter.collect::<String>() 
+        })
+      } 
+    }
+  }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pair { pub left: Tree, pub right: Tree }
+
+impl Pair {
+  pub fn parse_pairs(lines: &[String]) -> Vec<Pair> {
+    let grouped_lines: Vec<Vec<String>> = lines.iter().fold(
+      vec![vec![]], 
+      |mut acc, line| {
+        match line.as_str() {
+          "" => acc.push(vec![]),
+          _  => acc.last_mut().unwrap().push(line.to_string())
+        };
+        acc
+      }
+    );
+    assert_eq!(grouped_lines.len() as f32, (lines.len()+1) as f32/3.0);
+
+    let parse = |number: usize, line: &String| line.parse::<Tree>().unwrap_or_else(|err| panic!("Malformed packet on line {number}: {err}\n{line}"));
+    return grouped_lines.into_iter().enumerate().fold(
+      vec![],
+      |mut acc, (i, lines)| {
+        assert_eq!(lines.len(), 2);
+        acc.push(Pair { left: parse(3*i + 1, &lines[0]), right: parse(3*i + 2, &lines[1]) });
+        acc
+      }
+    );
+  }
+}
+
+impl Display for Pair {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+      write!(f, "Node pair:\n{}\n{}", &self.left, &self.right)
+  }
+}
+
+/// The pairs of packets, read from a JSON array of packets instead if the input is one, like the files written by `--export`.
+/// Pairs of packets never form one JSON value, since they take at least two lines, and only JSON starts with a line `[`.
+pub fn prepare(lines: &[String]) -> Vec<Pair> {
+  match json::read(&lines.join("\n")) {
+    Ok(trees)                                                                       => {
+      if trees.len() % 2 == 1 { panic!("{} JSON packets cannot be paired", trees.len()); }
+      trees.chunks(2).map(|pair| Pair { left: pair[0].clone(), right: pair[1].clone() }).collect()
+    },
+    Err(err) if lines.len() == 1 || lines.first().map(|line| line.trim()) == Some("[") => panic!("Malformed JSON packets: {err}"),
+    Err(_)                                                                          => Pair::parse_pairs(lines)
+  }
+}
+
+/// Write the packets to the file given by `--export` as a JSON array
+pub fn export(pairs: &[Pair], input: &Input) {
+  if let Some(file) = input.option::<String>("export") {
+    let trees: Vec<Tree> = pairs.iter().flat_map(|pair| [pair.left.clone(), pair.right.clone()]).collect();
+    fs::write(&file, json::write(&trees)).unwrap_or_else(|err| panic!("Failed to export the packets to {file}: {err}"));
+  }
+}
+
+/// The sum of the indices of the pairs in the right order, each explained with `--explain`
+pub fn one(pairs: &Vec<Pair>, input: &Input) -> String {
+  export(pairs, input);
+  if input.flag("explain") {
+    pairs.iter().enumerate().for_each(|(i, pair)| info!("Pair {}:\n{}", i+1, explain(&pair.left, &pair.right)));
+  }
+  return pairs.iter().enumerate().map(|(i,pair)| if pair.left.packet_cmp(&pair.right).is_lt() {i+1} else {0} ).sum::<usize>().to_string();
+}
+
+pub fn two(pairs: &Vec<Pair>, input: &Input) -> String {
+  export(pairs, input);
+  let mut packets = pairs.iter().fold(vec![], |mut acc, Pair {left, right}| { acc.push(Packet(left.clone())); acc.push(Packet(right.clone())); acc });
+  let (sep1, sep2) = (Packet(Tree::Node(vec![Tree::Node(vec![Tree::Leaf(2)])])), Packet(Tree::Node(vec![Tree::Node(vec![Tree::Leaf(6)])])));
+  packets.sort_unstable();
+
+  trace!("{}", packets.iter().fold("".to_string(), |acc, Packet(tree)| format!("{acc}\n{tree}")));
+
+  // The dividers would come after all smaller packets, and the second one also after the first one
+  let (index1, index2) = (packets.partition_point(|packet| *packet < sep1) + 1, packets.partition_point(|packet| *packet < sep2) + 2);
+  return (index1 * index2).to_string();
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+  type Parsed = Vec<Pair>;
+
+  fn prepare(lines: &[String]) -> Vec<Pair> { prepare(lines) }
+  fn one(parsed: &Vec<Pair>, input: &Input) -> String { one(parsed, input) }
+  fn two(parsed: &Vec<Pair>, input: &Input) -> String { two(parsed, input) }
+}
@@ -0,0 +1,74 @@
+use day13_22::{json, Packet, ParseError, Tree};
+use std::collections::BTreeSet;
+use proptest::prelude::*;
+use std::cmp::Ordering;
+
+/// Lists nested up to a few levels deep with integers as leaves
+fn tree() -> impl Strategy<Value = Tree> {
+  (-100..100i32).prop_map(Tree::Leaf).prop_recursive(4, 64, 5, 
+    |inner| prop::collection::vec(inner, 0..5).prop_map(Tree::Node)
+  )
+}
+
+/// A packet as it appears in the input, which is always a list
+fn packet() -> impl Strategy<Value = Tree> {
+  prop::collection::vec(tree(), 0..5).prop_map(Tree::Node)
+}
+
+proptest! {
+  #[test]
+  fn parse_display_round_trip(packet in packet()) {
+    prop_assert_eq!(Tree::parse_tree(&packet.to_string()), packet);
+  }
+}
+
+proptest! {
+  #[test]
+  fn order_is_total(a in packet(), b in packet(), c in packet()) {
+    prop_assert_eq!(a.packet_cmp(&b), b.packet_cmp(&a).reverse());
+    if a.packet_cmp(&b).is_le() && b.packet_cmp(&c).is_le() { prop_assert!(a.packet_cmp(&c).is_le()); }
+  }
+
+  #[test]
+  fn difference_agrees_with_order(a in packet(), b in packet()) {
+    prop_assert_eq!(a.difference(&b).map_or(Ordering::Equal, |(_, ordering)| ordering), a.packet_cmp(&b));
+  }
+
+  #[test]
+  fn json_round_trip(packets in prop::collection::vec(packet(), 0..5)) {
+    prop_assert_eq!(json::read(&json::write(&packets)), Ok(packets));
+  }
+
+  #[test]
+  fn packets_order_like_packet_cmp(a in packet(), b in packet()) {
+    prop_assert_eq!(Packet(a.clone()).cmp(&Packet(b.clone())), a.packet_cmp(&b));
+    prop_assert_eq!(Packet(a.clone()) == Packet(b.clone()), a.packet_cmp(&b).is_eq());
+  }
+
+  #[test]
+  fn number_equals_singleton_list(v in -100..100i32) {
+    prop_assert_eq!(Tree::Leaf(v).packet_cmp(&Tree::Node(vec![Tree::Node(vec![Tree::Leaf(v)])])), Ordering::Equal);
+  }
+}
+
+#[test]
+fn malformed_brackets_report_the_column() {
+  let column = |line: &str| line.parse::<Tree>().map_err(|ParseError { column, .. }| column);
+  assert_eq!(column("[1,[2,3]"), Err(1));
+  assert_eq!(column("[1,2]]"), Err(6));
+  assert_eq!(column("[1 [2]]"), Err(4));
+  assert_eq!(column("[1,,2]"), Err(4));
+  assert_eq!(column("[1,]"), Err(4));
+  assert_eq!(column("[a]"), Err(2));
+}
+
+#[test]
+fn packets_sort_and_dedup_in_packet_order() {
+  let packets: Vec<Packet> = ["[[2]]", "[1,1,3]", "[2]", "[[1],[2,3,4]]"].iter().map(|line| Packet(Tree::parse_tree(&line.to_string()))).collect();
+  // `[[2]]` and `[2]` are the same packet
+  let set: BTreeSet<Packet> = packets.iter().cloned().collect();
+  assert_eq!(set.len(), 3);
+  assert_eq!(set.iter().next(), Some(&packets[1]));
+  assert_eq!(packets.iter().max(), Some(&packets[0]));
+  assert_ne!(packets[0].0, packets[2].0);
+}
@@ -0,0 +1,336 @@
+use common::{Input, Solution};
+use log::trace;
+use std::cmp::{max, min};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::str::FromStr;
+
+pub type Point = (i32,i32);
+
+#[derive(Clone,Debug,PartialEq)]
+pub enum Unit { Rock, Source, Sand, LostSand }
+
+impl Display for Unit {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", match &self {
+      Unit::Rock     => '#',
+      Unit::Source   => '+',
+      Unit::Sand     => 'o',
+      Unit::LostSand => '~'
+    })
+  }
+}
+
+#[derive(Clone, Debug)]
+pub struct Cave { pub objects: HashMap<Point, Unit>, pub height: i32, pub has_bottom: bool }
+
+impl Cave {
+  pub fn new(lines: &[String], has_bottom: bool) -> Cave { 
+    fn to_drawings(lines: &[String]) -> Vec<Vec<Point>> {
+      lines.iter().map(|line| {
+        line.split(" -> ").map(
+          |p_string| {
+            let xy_list: Vec<i32> = p_string.split(',').map(|p| p.parse::<i32>().unwrap()).collect(); 
+            assert_eq!(xy_list.len(), 2);
+            (xy_list[0],xy_list[1])
+          }
+        ).collect()
+      }).collect()
+    }
+
+    fn to_line(here: Point, there: Point) -> Vec<Point> {
+      let reverse;
+      
+      let mut result: Vec<Point> = 
+        if here.0 == there.0 {
+          reverse = here.1 > there.1;
+          (min(there.1,here.1)..=max(there.1,here.1)).map(|y| (here.0, y)).collect()
+        } else if here.1 == there.1 {
+          reverse = here.0 > there.0;
+          (min(there.0,here.0)..=max(there.0,here.0)).map(|x| (x, here.1)).collect()
+        } else {
+          panic!("Could not produce a straight line from: {here:?} and {there:?}");
+        };
+
+      if reverse {
+        result.reverse();
+      }
+
+      return result;
+    }
+
+    let mut objects = HashMap::new();
+    let drawings = to_drawings(lines);
+
+    drawings.into_iter().for_each(
+      |drawing| {
+        let mut drawing_iter = drawing.into_iter();
+        if let Some(start) = drawing_iter.next() {
+          objects.insert(start, Unit::Rock);
+          drawing_iter.fold(
+            start,
+            |prev, next| {
+              let mut line_iter = to_line(prev, next).into_iter();
+              line_iter.next();
+              line_iter.for_each(|p| { objects.insert(p, Unit::Rock); });
+              next
+            }
+          );
+        }
+      }
+    );
+
+    objects.insert((500,0), Unit::Source);
+
+    let (_, (_, height)) = Cave::to_dimensions(&objects);
+
+    return Cave { objects: objects, height: height, has_bottom: has_bottom }
+  }
+
+  pub fn to_dimensions(objects: &HashMap<Point, Unit>) -> (Point, Point) {
+    let (mut xs, mut ys): (Vec<i32>, Vec<i32>) = objects.keys().map(|p| *p).unzip();
+
+    xs.sort_unstable();
+    ys.sort_unstable();
+ 
+    assert_ne!(xs.len(), 0);
+    assert_ne!(ys.len(), 0); 
+
+    return ((*xs.first().unwrap(),*ys.first().unwrap()),(*xs.last().unwrap(),*ys.last().unwrap()));
+  }
+
+  pub fn height(&self) -> i32 {(&self).height + if (&self).has_bottom {1} else {0} }
+  pub fn dimensions(&self) -> (Point, Point) { Cave::to_dimensions(&self.objects) }
+  pub fn end_reached(&self, p: Point) -> bool { p.1 == (&self).height() }
+
+  pub fn sand_flow(&mut self) {
+    fn sand_step(cave: &Cave, (x,y): Point) -> Option<Point> {
+      let mut result = None;
+      for (dx,dy) in vec![(0,1),(-1,1),(1,1)] {
+        let curr = (x+dx,y+dy);
+        let unit_option = cave.objects.get(&curr);
+        if unit_option.is_none() || unit_option.is_some() && (*unit_option.unwrap() == Unit::Source || *unit_option.unwrap() == Unit::LostSand) {
+          result = Some(curr);
+          break;
+        }
+      }
+      return result;
+    }  
+
+    let mut abyss_reached = false;
+    let mut sources: Vec<Point> = (&self).objects.iter().filter(|(_,u)| **u == Unit::Source).map(|(p,_)| *p).collect();
+
+    while !abyss_reached && !sources.is_empty() {
+      for i in 0..sources.len() {
+        let mut sand_pos = sources[i];
+        let mut fix = false;
+        while !fix {
+          if (&self).end_reached(sand_pos) {
+            fix = true;
+            abyss_reached = !(&self).has_bottom;
+          } else if let Some(new_pos) = sand_step(&self, sand_pos) {
+            sand_pos = new_pos;
+          } else {
+            fix = true;
+          }
+        }
+        sources = sources.into_iter().filter(|s| *s != sand_pos).collect();
+        self.objects.insert(sand_pos, if abyss_reached {Unit::LostSand} else {Unit::Sand});
+      }
+    }
+  }
+
+  pub fn count_sand(&self) -> usize {
+    (&self).objects.values().fold(0, |acc, u| acc + if *u == Unit::Sand {1} else {0})
+  }
+}
+
+impl Display for Cave {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+      let dims@(ul, dr) = (&self).dimensions();
+      write!(f, "{:?}{}", dims, ((ul.1)..=(&self).height()+1).fold(
+        String::new(),
+        |acc, y| {
+          format!("{acc}\n{}", ((ul.0)..=(dr.0)).fold(
+            String::new(),
+            |acc, x| {
+              format!("{acc}{}", 
+                if y == (&self).height()+1 {
+                  if (&self).has_bottom {
+                    "#"
+                  } else {
+                    "v"
+                  }.to_string()
+                } else {
+                  match (&self).objects.get(&(x,y)) {
+                    Some(unit) => unit.to_string(), 
+                    None       => ".".to_string()
+                  }
+                }
+              )
+            }
+          ))
+        }
+      )
+    )
+  }
+}
+
+pub const SOURCE: Point = (500,0);
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Cell { Air, Rock, Sand }
+
+/// The cave as a dense grid, wide enough for every point the sand can reach, even with a floor
+#[derive(Debug)]
+pub struct Grid { pub cells: Vec<Cell>, pub left: i32, pub width: usize, pub height: i32, pub has_bottom: bool }
+
+impl Grid {
+  pub fn new(cave: &Cave) -> Grid {
+    // With a floor, the sand piles up to a triangle, which is at most as wide as twice its height
+    let ((min_x, _), (max_x, _)) = cave.dimensions();
+    let spread = cave.height + 2;
+    let (left, right) = (min(min_x, SOURCE.0 - spread) - 1, max(max_x, SOURCE.0 + spread) + 1);
+    let width = (right - left + 1) as usize;
+
+    let mut grid = Grid { cells: vec![Cell::Air; width * (cave.height + 2) as usize], left, width, height: cave.height, has_bottom: cave.has_bottom };
+    cave.objects.iter().filter(|(_, unit)| **unit == Unit::Rock).for_each(|((x,y), _)| grid.set((*x,*y), Cell::Rock));
+    return grid;
+  }
+
+  fn index(&self, (x,y): Point) -> usize { y as usize * self.width + (x - self.left) as usize }
+
+  pub fn get(&self, p: Point) -> Cell { self.cells[self.index(p)] }
+
+  pub fn set(&mut self, p: Point, cell: Cell) { let i = self.index(p); self.cells[i] = cell; }
+
+  /// Whether sand can fall into the point, which it cannot below the lowest free row above the floor
+  pub fn free(&self, p@(_,y): Point) -> bool { y <= self.height+1 && self.get(p) == Cell::Air }
+
+  /// Pour sand until it falls into the abyss or blocks the source, and count the grains that came to rest.
+  /// Every grain follows the path of the grain before it up to the point above where that one came to rest,
+  /// so the path is kept and the next grain starts falling from there.
+  pub fn sand_flow(&mut self) -> usize {
+    let mut path  = vec![SOURCE];
+    let mut count = 0;
+    while let Some(&(x,y)) = path.last() {
+      if !self.has_bottom && y == self.height { break; }
+      match [(x,y+1), (x-1,y+1), (x+1,y+1)].into_iter().find(|next| self.free(*next)) {
+        Some(next) => path.push(next),
+        None       => { self.set((x,y), Cell::Sand); count += 1; path.pop(); }
+      }
+    }
+    return count;
+  }
+
+  /// Count the grains that come to rest above a floor without dropping them:
+  /// a point gets sand exactly if it is free and one of the three points above it gets sand
+  pub fn flood(&self) -> usize {
+    if !self.has_bottom { panic!("Flooding only works with a floor, since without one sand falls into the abyss"); }
+    let mut row = vec![false; self.width];
+    row[(SOURCE.0 - self.left) as usize] = true;
+    let mut count = 1;
+    for y in SOURCE.1+1..=self.height+1 {
+      row = (0..self.width).map(
+        |i| self.get((self.left + i as i32, y)) == Cell::Air && (row[i] || (i > 0 && row[i-1]) || row.get(i+1) == Some(&true))
+      ).collect();
+      count += row.iter().filter(|sand| **sand).count();
+    }
+    return count;
+  }
+}
+
+impl Display for Grid {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let rows: Vec<String> = self.cells.chunks(self.width).map(
+      |row| row.iter().map(|cell| match cell { Cell::Air => '.', Cell::Rock => '#', Cell::Sand => 'o' }).collect()
+    ).collect();
+    write!(f, "{}", rows.join("\n"))
+  }
+}
+
+/// How the sand is simulated
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Engine {
+  /// Drop every grain from the source into a map of the objects
+  Map,
+  /// Drop the grains into a dense grid, each from where the path of the last one branched
+  Grid,
+  /// Fill the grid row by row, which only works with a floor
+  Flood
+}
+
+impl FromStr for Engine {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s {
+      "map"   => Ok(Engine::Map),
+      "grid"  => Ok(Engine::Grid),
+      "flood" => Ok(Engine::Flood),
+      _       => Err(format!("Unknown engine `{s}`, expected `map`, `grid` or `flood`"))
+    }
+  }
+}
+
+/// The number of grains of sand that come to rest, simulated by the engine given by `--engine`
+pub fn count_sand(cave: &Cave, input: &Input, has_bottom: bool) -> usize {
+  let mut cave = Cave { has_bottom, ..cave.clone() };
+
+  match input.option("engine").unwrap_or(Engine::Grid) {
+    Engine::Map   => {
+      trace!("{cave}");
+      cave.sand_flow();
+      trace!("{cave}");
+      cave.count_sand()
+    },
+    Engine::Grid  => {
+      let mut grid = Grid::new(&cave);
+      let count    = grid.sand_flow();
+      trace!("\n{grid}");
+      count
+    },
+    Engine::Flood => Grid::new(&cave).flood()
+  }
+}
+
+pub fn one(cave: &Cave, input: &Input) -> String {
+  return count_sand(cave, input, false).to_string();
+}
+
+pub fn two(cave: &Cave, input: &Input) -> String {
+  return count_sand(cave, input, true).to_string();
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+  type Parsed = Cave;
+
+  fn prepare(lines: &[String]) -> Cave { Cave::new(lines, false) }
+  fn one(parsed: &Cave, input: &Input) -> String { one(parsed, input) }
+  fn two(parsed: &Cave, input: &Input) -> String { two(parsed, input) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::args::Part;
+
+    #[test]
+    fn engines_agree() {
+        // A few rock shapes, including one reaching past the spread of the floor
+        let caves = [
+            vec!["498,4 -> 498,6 -> 496,6", "503,4 -> 502,4 -> 502,9 -> 494,9"],
+            vec!["480,3 -> 520,3", "499,5 -> 501,5 -> 501,7"],
+            vec!["300,2 -> 300,12 -> 310,12", "500,8 -> 500,9"]
+        ];
+        for cave in caves {
+            let lines: Vec<String> = cave.iter().map(|line| line.to_string()).collect();
+            let count = |has_bottom: bool, engine: &str| count_sand(&Cave::new(&lines, false), &Input::new(Part::One, lines.clone()).with_options(&["--engine", engine]), has_bottom);
+            assert_eq!(count(false, "grid"), count(false, "map"));
+            assert_eq!(count(true, "grid"), count(true, "map"));
+            assert_eq!(count(true, "flood"), count(true, "map"));
+        }
+    }
+}
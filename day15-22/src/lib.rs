@@ -0,0 +1,237 @@
+use common::{Input, Solution};
+use log::{info, trace};
+use std::cmp::{min, max};
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::str::FromStr;
+
+pub type Point = (i64,i64);
+
+#[derive(Debug)]
+pub struct Sensor { pub pos: Point, pub beacon: Point }
+
+impl Sensor {
+  pub fn new(line: &str) -> Sensor {
+    let (_, s) = line.split_at(12);
+    let positions: Vec<Point> = s.split(": closest beacon is at x=").map(
+      |pos_string| {
+        let nums: Vec<&str> = pos_string.split(", y=").collect();
+        assert_eq!(nums.len(),2);
+        (nums[0].parse::<i64>().unwrap(), nums[1].parse::<i64>().unwrap())
+      }
+    ).collect();
+    assert_eq!(positions.len(), 2);
+    Sensor { pos: positions[0], beacon: positions[1] }
+  }
+
+  pub fn radius(&self) -> i64 {
+    (self.pos.0.abs_diff(self.beacon.0) + 
+     self.pos.1.abs_diff(self.beacon.1)) as i64
+  }
+
+  pub fn covers(&self, (x,y): Point) -> bool {
+    ((self.pos.0.abs_diff(x) + self.pos.1.abs_diff(y)) as i64) <= self.radius()
+  }
+
+  pub fn y_range(&self, y: i64, discard_beacon: bool) -> Option<(i64, i64)> {
+    let y_distance = if self.pos.1 < y {(self.pos.1 + self.radius()) - y} else {y - (self.pos.1 - self.radius())};
+    if y_distance < 0 { return None; }
+    let (mut lower, mut upper) = (self.pos.0-y_distance, self.pos.0+y_distance);
+    if discard_beacon {
+      if (lower, y) == self.beacon {
+        lower += 1;
+      } else if (upper, y) == self.beacon {
+        upper -= 1;
+      }
+    }
+
+    //trace!("Range of {} on y={y} is {:?} [y_distance={y_distance}]", &self, if lower <= upper {Some((lower,upper))} else {None});
+
+    if lower <= upper {Some((lower,upper))} else {None}
+  }
+}
+
+impl Display for Sensor {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "(S:({:>2},{:>2}),B:({:>2},{:>2}),r:{:>2})", self.pos.0, self.pos.1, self.beacon.0, self.beacon.1, self.radius())
+  }
+}
+
+pub fn prepare(lines: &[String]) -> Vec<Sensor> {
+  lines.iter().filter(|line| !line.is_empty()).map(String::as_str).map(Sensor::new).collect()
+}
+
+/// The row of part one, unless given with `--row Y`
+pub const ROW  : i64 = 2000000;
+/// The largest coordinate of the search area of part two, unless given with `--bound B`
+pub const BOUND: i64 = 4000000;
+
+/// Draw the area like the puzzle does: sensors, beacons, the covered positions and the distress beacon
+pub fn render(sensors: &[Sensor], (x_min, y_min): Point, (x_max, y_max): Point, distress: Option<Point>) -> String {
+  let width = max(y_min.to_string().len(), y_max.to_string().len());
+  (y_min..=y_max).map(
+    |y| format!("{y:>width$} {}", (x_min..=x_max).map(
+      |x| {
+        if Some((x,y)) == distress                                 { '!' }
+        else if sensors.iter().any(|sensor| sensor.pos    == (x,y)) { 'S' }
+        else if sensors.iter().any(|sensor| sensor.beacon == (x,y)) { 'B' }
+        else if sensors.iter().any(|sensor| sensor.covers((x,y)))   { '#' }
+        else                                                        { '.' }
+      }
+    ).collect::<String>())
+  ).collect::<Vec<String>>().join("\n")
+}
+
+/// Render the area with `--render`, as long as it is small enough to look at
+pub fn print_area(input: &Input, sensors: &[Sensor], from: Point, to: Point, distress: Option<Point>) {
+  if !input.flag("render") { return; }
+  if (to.0 - from.0 + 1) * (to.1 - from.1 + 1) > 100000 {
+    info!("The area from {from:?} to {to:?} is too large to render");
+  } else {
+    info!("Coverage from {from:?} to {to:?}:\n{}", render(sensors, from, to, distress));
+  }
+}
+
+pub fn one(sensors: &[Sensor], input: &Input) -> String {
+  let y       = input.option("row").unwrap_or(ROW);
+  let points  = sensors.iter().flat_map(|sensor| [sensor.pos, sensor.beacon]);
+  print_area(input, sensors, 
+    (points.clone().map(|(x,_)| x).min().unwrap_or(0), points.clone().map(|(_,y)| y).min().unwrap_or(0)),
+    (points.clone().map(|(x,_)| x).max().unwrap_or(0), points.map(|(_,y)| y).max().unwrap_or(0)),
+    None
+  );
+  //trace!("y={y}");
+  //trace!("{}", sensors.iter().fold(String::new(), |acc, sensor| format!("{acc}\n{sensor}")));
+  let ranges = sensors.iter().map(|sensor| { sensor.y_range(y,true) }); 
+  let mut merged_ranges: Vec<(i64,i64)> = ranges.fold(
+    HashSet::new(),
+    |mut acc, range_option| {
+      if let Some(range@(lower,upper)) = range_option {
+        let to_merge: Vec<(i64, i64)> = acc.iter().filter(
+          |(l,u)| *l-1     <= lower && lower <= *u+1     || *l-1     <= upper && upper <= *u+1 || 
+                   lower-1 <= *l    && *l    <=  upper+1 ||  lower-1 <= *u    && *u    <=  upper+1
+        ).map(|(l,u)| (*l,*u)).collect();
+        trace!("Add {range:?} to {acc:?}\nto_merge: {to_merge:?}");
+        if to_merge.is_empty() {
+          acc.insert(range);
+        } else {
+          let new_range = (
+            min(lower, to_merge.iter().min_by(|(l1,_), (l2,_)| l1.cmp(l2)).unwrap().0), 
+            max(upper, to_merge.iter().max_by(|(_,u1), (_,u2)| u1.cmp(u2)).unwrap().1)
+          );
+          to_merge.into_iter().for_each(|range| { acc.remove(&range); } );
+          acc.insert(new_range);
+        }
+      }
+      acc
+    }
+  ).into_iter().collect();
+  merged_ranges.sort();
+  trace!("{}", merged_ranges.iter().fold(String::new(), |acc,(lower,upper)| format!("{acc}({lower},{upper}) ")));
+  merged_ranges.into_iter().map(|(l,u)| u-l+1).sum::<i64>().to_string()
+}
+
+/// How part two searches for the distress beacon
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Strategy { 
+  /// Merge the ranges covered by the sensors on every row, until a row has a gap
+  Rows, 
+  /// Intersect the lines just outside of the sensor ranges
+  Lines 
+}
+
+impl FromStr for Strategy {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s {
+      "rows"  => Ok(Strategy::Rows),
+      "lines" => Ok(Strategy::Lines),
+      _       => Err(format!("Unknown strategy `{s}`, expected `rows` or `lines`"))
+    }
+  }
+}
+
+pub fn scan_rows(sensors: &[Sensor], bound: i64) -> Option<Point> {
+  for y in 0..=bound {
+    let ranges = sensors.iter().map(|sensor| { sensor.y_range(y,false) }); 
+    let merged_ranges: Vec<(i64,i64)> = ranges.fold(
+      HashSet::new(),
+      |mut acc, range_option| {
+        if let Some(range@(mut lower, mut upper)) = range_option {
+            lower = max(0,lower);
+            upper = min(bound,upper);
+            let to_merge: Vec<(i64, i64)> = acc.iter().filter(
+              |(l,u)| *l-1     <= lower && lower <= *u+1     || *l-1     <= upper && upper <= *u+1 || 
+                       lower-1 <= *l    && *l    <=  upper+1 ||  lower-1 <= *u    && *u    <=  upper+1
+            ).map(|(l,u)| (*l,*u)).collect();
+            if to_merge.is_empty() {
+              acc.insert(range);
+            } else {
+              let new_range = (
+                min(lower, to_merge.iter().min_by(|(l1,_), (l2,_)| l1.cmp(l2)).unwrap().0), 
+                max(upper, to_merge.iter().max_by(|(_,u1), (_,u2)| u1.cmp(u2)).unwrap().1)
+              );
+              to_merge.into_iter().for_each(|range| { acc.remove(&range); } );
+              acc.insert(new_range);
+            }
+          };      
+        acc
+      }
+    ).into_iter().collect();
+    if merged_ranges.len() == 2 {
+      trace!("On y={y} between {merged_ranges:?}");
+      let x = max(merged_ranges[0].0,merged_ranges[1].0) - 1;
+      return Some((x, y));
+    }
+  }
+  None
+}
+
+/// The distress beacon is the only uncovered position, so unless it is in a corner of the search area, 
+/// it lies just outside of the ranges of several sensors, where an ascending and a descending boundary cross.
+pub fn intersect_lines(sensors: &[Sensor], bound: i64) -> Option<Point> {
+  // Ascending lines y = x + c and descending lines y = -x + d at distance radius+1 around each sensor
+  let (ascending, descending): (Vec<i64>, Vec<i64>) = sensors.iter().flat_map(
+    |sensor| {
+      let ((x,y), r) = (sensor.pos, sensor.radius() + 1);
+      [(y-x-r, y+x-r), (y-x+r, y+x+r)]
+    }
+  ).unzip();
+
+  let corners = [(0,0), (0,bound), (bound,0), (bound,bound)];
+  let crossings = ascending.iter().flat_map(
+    |c| descending.iter().filter(move |d| (*d - c) % 2 == 0).map(move |d| ((d-c)/2, (d+c)/2))
+  );
+
+  corners.into_iter().chain(crossings).find(
+    |point@(x,y)| (0..=bound).contains(x) && (0..=bound).contains(y) && sensors.iter().all(|sensor| !sensor.covers(*point))
+  )
+}
+
+/// Search with `--strategy rows` or `--strategy lines` (default)
+pub fn two(sensors: &[Sensor], input: &Input) -> String {
+  let bound    = input.option("bound").unwrap_or(BOUND);
+  let strategy = input.option("strategy").unwrap_or(Strategy::Lines);
+  let beacon   = match strategy {
+    Strategy::Rows  => scan_rows(sensors, bound),
+    Strategy::Lines => intersect_lines(sensors, bound)
+  };
+  trace!("Found the distress beacon at {beacon:?} with {strategy:?}");
+  print_area(input, sensors, (0,0), (bound,bound), beacon);
+
+  match beacon {
+    Some((x,y)) => (x * 4000000 + y).to_string(),
+    None        => "No unseen position found".to_string()
+  }
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+  type Parsed = Vec<Sensor>;
+
+  fn prepare(lines: &[String]) -> Vec<Sensor> { prepare(lines) }
+  fn one(parsed: &Vec<Sensor>, input: &Input) -> String { one(parsed, input) }
+  fn two(parsed: &Vec<Sensor>, input: &Input) -> String { two(parsed, input) }
+}
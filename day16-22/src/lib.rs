@@ -0,0 +1,241 @@
+use common::{Input, Solution};
+use std::cmp::max;
+use std::collections::{HashSet, HashMap};
+use std::fmt::Display;
+
+pub type ValveId = usize;
+pub type Flow = u32;
+pub type Time = u32;
+
+#[derive(Debug)]
+pub struct Valve { 
+  pub id       : ValveId, 
+  pub name     : String, 
+  pub flow     : Flow, 
+  pub neighbors: HashSet<ValveId> 
+}
+
+impl Valve {
+  pub fn new(id: ValveId, name: String, flow: Flow, neighbors: HashSet<ValveId>) -> Valve {
+    Valve { id, name, flow, neighbors }
+  }
+}
+
+impl Display for Valve {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let mut neighbor_iter = self.neighbors.iter();
+    let first_neighbor = if let Some(nid) = neighbor_iter.next() {
+      nid.to_string()
+    } else {
+      String::new()
+    };
+    write!(f, "({:>2} {} {:>2}) -> [{}]", 
+      self.id, 
+      self.name, 
+      self.flow, 
+      neighbor_iter.fold(
+        first_neighbor,
+        |acc, nid| {
+          format!("{acc}, {nid}")
+        }
+      )
+    )
+  } 
+}
+
+pub fn prepare(lines: &[String]) -> (Vec<Valve>, HashMap<String,ValveId>) {
+  fn parse(line: &String) -> (String, Flow, HashSet<String>) {
+    let mut first_split: Vec<&str> = line.split("; tunnels lead to valves ").collect();
+    if first_split.len() == 1 {
+      first_split = line.split("; tunnel leads to valve ").collect();
+    }
+    assert_eq!(first_split.len(), 2);
+    let second_split: Vec<&str> = first_split[0].split(" has flow rate=").collect();
+    assert_eq!(second_split.len(), 2);
+    
+
+    return (second_split[0].split("Valve ").nth(1).unwrap().to_string(), 
+            second_split[1].parse::<Flow>().unwrap(), 
+            first_split[1].split(", ").map(|e| e.to_string()).collect());
+  }
+
+  let mut valve_parsed = vec![];
+  let mut name_ids = HashMap::new();
+  lines.iter().enumerate().for_each(
+    |(i,line)| {
+      let (name, flow, neighbor_strings) = parse(line);
+      valve_parsed.push((name.to_string(), flow, neighbor_strings));
+      name_ids.insert(name, i);
+    }
+  );
+  
+  let mut valves = vec![];
+
+  valve_parsed.iter().enumerate().for_each(
+    |(i, (name, flow, neighbor_names))| {
+      valves.push(Valve::new(
+        i, 
+        name.to_string(), 
+        *flow, 
+        neighbor_names.iter().map(
+          |name| 
+            name_ids[name]
+        ).collect::<HashSet<ValveId>>()));
+    }
+  );
+  return (valves, name_ids);
+}
+
+#[derive(Debug)]
+pub struct Distance { pub mins: Time, pub valve_id: ValveId }
+
+pub fn distance_matrix(valves: &Vec<Valve>) -> Vec<Vec<Distance>>{
+  let mut distance_matrix = vec![];
+  let useless = valves.iter().filter(|v| v.flow == 0).fold(
+    HashSet::new(), 
+    |mut acc, v| {
+      acc.insert(v.id); 
+      acc
+    }
+  );
+  valves.iter().for_each(
+    |valve| {
+      let mut mins: Time = 1;
+      let mut visited = HashSet::new();
+      let mut distances = Vec::new();
+      let mut to_visit = HashSet::new();
+      to_visit.insert(valve.id);
+
+      while !to_visit.is_empty() {
+        let mut next_visit = HashSet::new();
+        to_visit.iter().for_each(
+          |id| {
+            distances.push(Distance { mins: mins, valve_id: *id });
+            visited.insert(*id);
+            (&(&valves[*id].neighbors - &visited) - &to_visit).into_iter().for_each(|id| { next_visit.insert(id); } );
+          }
+        );
+        to_visit = next_visit;
+        mins += 1;
+      }
+      distances = distances.into_iter().filter(|d| !useless.contains(&d.valve_id)).collect();
+      //distances.sort_by(|d1,d2| d1.mins.cmp(&d2.mins));
+      assert_eq!(distances.len(), valves.len() - useless.len());
+      distance_matrix.push(distances);
+    }
+  );
+  return distance_matrix;
+}
+
+/// A set of the valves with positive flow, as bits
+pub type Valves = u64;
+
+/// `best_per_set` keeps a pressure for each of the 2^n sets of valves, and every extra agent merges them in O(3^n).
+/// With this many valves with positive flow that is 2^16 entries and about 4.3·10^7 merge steps per agent.
+pub const MAX_USEFUL: usize = 16;
+
+/// Only the valves with positive flow matter, so they are numbered from 0 to use them in a `Valves` set. 
+/// The start valve comes after them.
+pub struct Network { pub flows: Vec<Flow>, pub distances: Vec<Vec<Time>>, pub start: usize }
+
+impl Network {
+  pub fn new(valves: &Vec<Valve>, distance_matrix: &Vec<Vec<Distance>>, start: ValveId) -> Network {
+    let useful: Vec<ValveId> = valves.iter().filter(|valve| valve.flow > 0).map(|valve| valve.id).collect();
+    assert!(useful.len() <= MAX_USEFUL, "{} valves with positive flow, but at most {MAX_USEFUL} are supported", useful.len());
+    let distances = useful.iter().chain([start].iter()).map(
+      |from| useful.iter().map(
+        |to| distance_matrix[*from].iter().find(|distance| distance.valve_id == *to).unwrap().mins
+      ).collect()
+    ).collect();
+
+    Network { flows: useful.iter().map(|id| valves[*id].flow).collect(), distances, start: useful.len() }
+  }
+
+  pub fn all(&self) -> Valves { (1 << self.flows.len()) - 1 }
+
+  /// The most pressure a single agent releases by opening exactly the valves of each set. 
+  /// Walks from valve to valve, remembering the best pressure per (position, time left, opened valves) 
+  /// to drop every path that reaches a state with less.
+  pub fn best_per_set(&self, total_mins: Time) -> Vec<Flow> {
+    let mut best = vec![0; self.all() as usize + 1];
+    let mut seen: HashMap<(usize, Time, Valves), Flow> = HashMap::new();
+    let mut steps = vec![(self.start, total_mins, 0 as Valves, 0)];
+
+    while let Some((pos, mins_left, opened, released)) = steps.pop() {
+      best[opened as usize] = max(best[opened as usize], released);
+      (0..self.flows.len()).filter(|valve| opened & 1 << valve == 0 && self.distances[pos][*valve] < mins_left).for_each(
+        |valve| {
+          let mins_left = mins_left - self.distances[pos][valve];
+          let released  = released + self.flows[valve] * mins_left;
+          let opened    = opened | 1 << valve;
+          if seen.get(&(valve, mins_left, opened)).map_or(true, |before| *before < released) {
+            seen.insert((valve, mins_left, opened), released);
+            steps.push((valve, mins_left, opened, released));
+          }
+        }
+      );
+    }
+
+    return best;
+  }
+
+  /// The most pressure the agents release together, where each agent opens a different set of valves
+  pub fn release_pressure(&self, total_mins: Time, agents: usize) -> Flow {
+    assert!(agents > 0, "At least one agent has to open valves");
+    let mut best = self.best_per_set(total_mins);
+
+    // Opening only some of the valves of a set is allowed as well
+    for valve in 0..self.flows.len() {
+      for set in 0..best.len() {
+        if set & 1 << valve != 0 { best[set] = max(best[set], best[set ^ 1 << valve]); }
+      }
+    }
+
+    // Split each set between one more agent and those before
+    let mut combined = best.clone();
+    for _ in 1..agents {
+      combined = (0..best.len()).map(
+        |set| {
+          let mut result = 0;
+          let mut subset = set;
+          loop {
+            result = max(result, best[subset] + combined[set ^ subset]);
+            if subset == 0 { break; }
+            subset = (subset - 1) & set;
+          }
+          result
+        }
+      ).collect();
+    }
+
+    return combined[self.all() as usize];
+  }
+}
+
+/// Open valves with `--agents N` (default 1 in part one, 2 in part two) 
+/// within `--time T` minutes (default 30 in part one, 26 in part two)
+pub fn release_pressure((valves, string_map): &(Vec<Valve>, HashMap<String,ValveId>), input: &Input, agents: usize, total_mins: Time) -> Flow {
+  let distance_matrix = distance_matrix(valves);
+  let network = Network::new(valves, &distance_matrix, string_map["AA"]);
+
+  return network.release_pressure(input.option("time").unwrap_or(total_mins), input.option("agents").unwrap_or(agents));
+}
+
+pub fn one(network: &(Vec<Valve>, HashMap<String,ValveId>), input: &Input) -> String {
+  return release_pressure(network, input, 1, 30).to_string();
+}
+
+pub fn two(network: &(Vec<Valve>, HashMap<String,ValveId>), input: &Input) -> String {
+  // Teaching the elephant takes 4 minutes
+  return release_pressure(network, input, 2, 26).to_string();
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+  type Parsed = (Vec<Valve>, HashMap<String,ValveId>);
+
+  fn prepare(lines: &[String]) -> (Vec<Valve>, HashMap<String,ValveId>) { prepare(lines) }
+  fn one(parsed: &(Vec<Valve>, HashMap<String,ValveId>), input: &Input) -> String { one(parsed, input) }
+  fn two(parsed: &(Vec<Valve>, HashMap<String,ValveId>), input: &Input) -> String { two(parsed, input) }
+}
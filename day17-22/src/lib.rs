@@ -0,0 +1,263 @@
+use common::{Input, Solution};
+use log::trace;
+use std::cmp::min;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt::Display;
+
+pub type Int = usize;
+pub type Point = (Int,Int);
+
+pub enum Push { Left, Right }
+
+impl Push {
+  pub fn apply(&self, x: Int, piece_width: Int, board_width: Int) -> Int {
+    match self {
+      Push::Left  if x > 0                       => x-1,
+      Push::Right if x+piece_width < board_width => x+1,
+      _                                          => x
+    }
+  }
+
+  pub fn push_no_collision(&self, x: Int, piece_width: Int, board_width: Int, push: Int, push_count: usize) -> (Int, Int) {
+    (self.apply(x, piece_width, board_width), (push+1)%push_count)
+  }
+
+  pub fn push(&self, pos@(x,_): (Int,Int), piece: &Piece, board: &Board, push: Int, push_count: usize) -> (Int, Int) {
+    (match self {
+       Push::Left  if !board.left_collision(pos, piece)  => x-1,
+       Push::Right if !board.right_collision(pos, piece) => x+1,
+       _                                                 => x
+     }, (push+1)%push_count)
+  }
+
+}
+
+impl Display for Push {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", 
+      match self {
+        Push::Left  => '<', 
+        Push::Right => '>'  
+      }
+    )
+  }
+}
+
+#[derive(Debug,Clone)]
+pub struct Piece { pub parts: HashSet<Point>, pub left: Vec<Int>, pub right: Vec<Int>, pub bottom: Vec<Int> }
+
+impl Piece {
+  pub fn new(parts: HashSet<Point>) -> Piece {
+    let width  = parts.iter().max_by(|(x1,_), (x2,_)| x1.cmp(x2)).unwrap().0+1;
+    let height = parts.iter().max_by(|(_,y1), (_,y2)| y1.cmp(y2)).unwrap().1+1;
+
+    let (left,right) = (0..height).into_iter().fold(
+      (vec![],vec![]),
+      |(mut left, mut right), i| {
+         left.push(parts.iter().filter(|(_,y)| *y == i).min_by(|(x1,_), (x2,_)| x1.cmp(x2)).unwrap().0);
+        right.push(parts.iter().filter(|(_,y)| *y == i).max_by(|(x1,_), (x2,_)| x1.cmp(x2)).unwrap().0);
+        (left, right)
+      }
+    );
+    let bottom = (0..width).into_iter().fold(
+      vec![],
+      |mut bottom, i| {
+        bottom.push(parts.iter().filter(|(x,_)| *x == i).min_by(|(_,y1), (_,y2)| y1.cmp(y2)).unwrap().1);
+        bottom
+      }
+    );
+
+    return Piece { parts, left, right, bottom };
+  }
+
+  pub fn width(&self)  -> Int { self.bottom.len() as Int }
+  pub fn height(&self) -> Int { self.left.len() as Int }
+}
+
+
+pub fn prepare(lines: &[String]) -> Vec<Push> {
+  assert_eq!(lines.len(), 1);
+  return lines[0].chars().map(
+    |c| {
+      match c {
+        '<' => Push::Left,
+        '>' => Push::Right,
+         _  => panic!("Read something other than `<` or `>` in input")
+      }
+    }
+  ).collect();
+}
+
+pub fn pieces() -> Vec<Piece> {
+  vec![
+    Piece::new(vec![(0,0),(1,0),(2,0),(3,0)].into_iter().collect()),
+    Piece::new(vec![(1,0),(0,1),(1,1),(2,1),(1,2)].into_iter().collect()),
+    Piece::new(vec![(0,0),(1,0),(2,0),(2,1),(2,2)].into_iter().collect()),
+    Piece::new(vec![(0,0),(0,1),(0,2),(0,3)].into_iter().collect()),
+    Piece::new(vec![(0,0),(1,0),(0,1),(1,1)].into_iter().collect())
+  ]
+}
+
+/// The rows of the tower that falling pieces can still reach.
+/// Rows below those are dropped and only counted.
+pub struct Board { pub pieces: VecDeque<Vec<bool>>, pub width: Int, pub dropped: Int } 
+
+impl Board {
+  pub fn new(width: Int) -> Board { Board { pieces: VecDeque::new(), width, dropped: 0 } }
+  pub fn height(&self) -> Int { self.dropped + self.pieces.len() as Int }
+  pub fn collision(&self, x: Int, y: Int) -> bool { 
+    y < self.dropped || y < self.height() && x < self.width && self.pieces[y-self.dropped][x] 
+  }
+
+  pub fn down_collision(&self, (x,y): (Int,Int), piece: &Piece) -> bool {
+    if y == 0 { return true; }
+    return !piece.bottom.iter().enumerate().all(|(dx,dy)| !self.collision(x+dx,y+dy-1));
+  }
+
+  pub fn left_collision(&self, (x,y): (Int,Int), piece: &Piece) -> bool {
+    if x == 0 { return true; }
+    return !piece.left.iter().enumerate().all(|(dy,dx)| !self.collision(x+dx-1,y+dy));
+  }
+
+  pub fn right_collision(&self, (x,y): (Int,Int), piece: &Piece) -> bool {
+    if x+piece.width() == self.width { return true; }
+    return !piece.right.iter().enumerate().all(|(dy,dx)| !self.collision(x+dx+1,y+dy));
+  }
+
+  pub fn place_at(&mut self, (x,y): (Int,Int)) {
+    assert!(self.dropped <= y && y < self.height());
+    assert!(x < self.width);
+    self.pieces[y-self.dropped][x] = true;
+  }
+
+  pub fn place_piece(&mut self, (x,y): (Int,Int), piece: &Piece) {
+    while self.height() < y + piece.height() {
+      self.pieces.push_back(vec![false; self.width]);
+    }
+
+    piece.parts.iter().for_each(
+      |(dx,dy)| {
+        self.place_at((x+dx,y+dy));
+      }
+    );
+  }
+
+  /// Flood the free space from above the tower and 
+  /// drop every row below the lowest flooded one, except the row it rests on
+  pub fn drop_unreachable(&mut self) {
+    let rows = self.pieces.len();
+    let mut lowest = rows;
+    let mut flooded = vec![vec![false; self.width]; rows];
+    let mut to_visit: Vec<Point> = (0..self.width).map(|x| (x, rows)).collect();
+
+    while let Some((x,y)) = to_visit.pop() {
+      lowest = min(lowest, y);
+      let mut neighbors = vec![(x,y+1)];
+      if x > 0            { neighbors.push((x-1,y)); }
+      if x+1 < self.width { neighbors.push((x+1,y)); }
+      if y > 0            { neighbors.push((x,y-1)); }
+      neighbors.into_iter().filter(|(_,ny)| *ny < rows).for_each(
+        |(nx,ny)| {
+          if !self.pieces[ny][nx] && !flooded[ny][nx] {
+            flooded[ny][nx] = true;
+            to_visit.push((nx,ny));
+          }
+        }
+      );
+    }
+
+    let unreachable = lowest.saturating_sub(1);
+    self.pieces.drain(..unreachable);
+    self.dropped += unreachable;
+  }
+}
+
+impl Display for Board {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "\n{}", self.pieces.iter().fold(
+      String::new(),
+      |acc, line| {
+        format!("{}\n{acc}", line.iter().fold(
+          String::new(), 
+          |acc, b| {
+            format!("{acc}{}", if *b {'#'} else {'.'})
+          }
+        ))
+      }
+    ))
+  }
+}
+
+/// Drop the pieces onto the board. 
+/// Once the next piece, the next push and the reachable rows repeat a previous state, 
+/// the tower grows the same way again, so whole cycles are skipped.
+pub fn run(pushes: &Vec<Push>, pieces: &Vec<Piece>, pieces_required: usize) -> Board {
+  let (spawn_x, spawn_height) = (2,3);
+  let mut board = Board::new(7);
+  let mut p: usize = 0;
+  let mut push = 0;
+  let mut seen: HashMap<(usize, usize, VecDeque<Vec<bool>>), (usize, Int)> = HashMap::new();
+  let mut cycle_skipped = false;
+
+  while p < pieces_required {
+    let piece = &pieces[p%pieces.len()];
+    let (mut x, mut y) = (spawn_x, board.height() + spawn_height);
+
+    // First steps without collision
+    while y > board.height() {
+      (x, push) = pushes[push].push_no_collision(x,piece.width(),board.width,push,pushes.len());
+      y -= 1; 
+    }
+    (x, push) = pushes[push].push_no_collision(x,piece.width(),board.width,push,pushes.len());
+
+    // Now, repeat down and push until a collision is ahead
+    while !board.down_collision((x,y), piece) {
+      y -= 1;
+      (x, push) = pushes[push].push((x,y),piece,&board,push,pushes.len());
+    }
+
+    // Place piece
+    board.place_piece((x,y), piece);
+    board.drop_unreachable();
+    p += 1;
+
+    //trace!("{board}");
+
+    if !cycle_skipped {
+      if let Some((prev_p, prev_height)) = seen.insert((p%pieces.len(), push, board.pieces.clone()), (p, board.height())) {
+        let (cycle_pieces, cycle_height) = (p-prev_p, board.height()-prev_height);
+        let cycles = (pieces_required-p)/cycle_pieces;
+        trace!("Pieces {prev_p} to {p} repeat, growing the tower by {cycle_height}. Skip {cycles} cycles");
+        p += cycles*cycle_pieces;
+        board.dropped += cycles*cycle_height;
+        cycle_skipped = true;
+      }
+    }
+  }
+
+  return board;
+}
+
+pub fn one(pushes: &Vec<Push>, _input: &Input) -> String {
+  let pieces = pieces();
+  let board  = run(pushes, &pieces, 2022);
+
+  return board.height().to_string();
+}
+
+pub fn two(pushes: &Vec<Push>, _input: &Input) -> String {
+  let pieces = pieces();
+  let board  = run(pushes, &pieces, 1000000000000);
+
+  return board.height().to_string();
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+  type Parsed = Vec<Push>;
+
+  fn prepare(lines: &[String]) -> Vec<Push> { prepare(lines) }
+  fn one(parsed: &Vec<Push>, input: &Input) -> String { one(parsed, input) }
+  fn two(parsed: &Vec<Push>, input: &Input) -> String { two(parsed, input) }
+}
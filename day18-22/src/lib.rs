@@ -0,0 +1,132 @@
+use common::{Input, Solution};
+use std::collections::HashSet;
+
+pub type Int = i64;
+pub type Square = (Int,Int);
+pub type Cube = (Int,Int,Int);
+pub type Dims = (Int,Int,Int,Int,Int,Int);
+
+pub enum Axis {X, Y, Z}
+
+pub fn prepare(lines: &[String]) -> HashSet<Cube> {
+  lines.iter().map(
+    |line| {
+      let pos_list: Vec<Int> = line.split(',').map(|v| v.parse::<Int>().unwrap()).collect();
+      assert_eq!(pos_list.len(), 3);
+      (pos_list[0],pos_list[1],pos_list[2])
+    }
+  ).collect()
+}
+
+pub fn scan_surface(cubes: &HashSet<Cube>, start: Int, end: Int, reversed: bool, axis: Axis) -> usize {
+  let mut steps: Vec<Int> = (start..=end).collect();
+  if reversed { steps.reverse(); }
+
+  let mut prev = HashSet::new();
+  let mut result = 0;
+
+  for c in steps {
+    let curr: HashSet<Square> = cubes.iter().filter(
+      |(x,y,z)| {
+        match axis {
+          Axis::X => *x == c,
+          Axis::Y => *y == c,
+          Axis::Z => *z == c
+        }
+      }
+    ).map(
+      |(x,y,z)| {
+        match axis {
+          Axis::X => (*y,*z),
+          Axis::Y => (*x,*z),
+          Axis::Z => (*x,*y)
+        }
+      }
+    ).collect();
+    result += (&curr - &prev).len();
+    prev = curr;
+  }
+
+  return result;
+}
+
+
+pub fn dimensions(cubes: &HashSet<Cube>) -> Dims {
+  let back   = cubes.iter().min_by(|(_,_,z1), (_,_,z2)| z1.cmp(z2)).unwrap().2;
+  let front  = cubes.iter().max_by(|(_,_,z1), (_,_,z2)| z1.cmp(z2)).unwrap().2;
+  let bottom = cubes.iter().min_by(|(_,y1,_), (_,y2,_)| y1.cmp(y2)).unwrap().1;
+  let top    = cubes.iter().max_by(|(_,y1,_), (_,y2,_)| y1.cmp(y2)).unwrap().1;
+  let left   = cubes.iter().min_by(|(x1,_,_), (x2,_,_)| x1.cmp(x2)).unwrap().0;
+  let right  = cubes.iter().max_by(|(x1,_,_), (x2,_,_)| x1.cmp(x2)).unwrap().0;
+
+  return (back, front, bottom, top, left, right);
+}
+
+pub fn one(cubes: &HashSet<Cube>, _input: &Input) -> String {
+  let (back, front, bottom, top, left, right) = dimensions(cubes);
+
+  let mut total = 0;
+
+  total += scan_surface(&cubes, left, right, false, Axis::X);
+  total += scan_surface(&cubes, left, right, true , Axis::X);
+  total += scan_surface(&cubes, bottom, top, false, Axis::Y);
+  total += scan_surface(&cubes, bottom, top, true , Axis::Y);
+  total += scan_surface(&cubes, back, front, false, Axis::Z);
+  total += scan_surface(&cubes, back, front, true , Axis::Z);
+
+  return total.to_string();
+}
+
+pub fn two(cubes: &HashSet<Cube>, _input: &Input) -> String {
+  fn neighbors((x,y,z): &Cube, (back, front, bottom, top, left, right): &Dims) -> Vec<Cube> {
+    let mut result = vec![];
+    if x >= left {
+      result.push((*x-1,*y,*z));
+    }  
+    if x <= right {
+      result.push((*x+1,*y,*z));
+    }  
+    if y >= bottom {
+      result.push((*x,*y-1,*z));
+    }  
+    if y <= top {
+      result.push((*x,*y+1,*z));
+    }  
+    if z >= back {
+      result.push((*x,*y,*z-1));
+    }  
+    if z <= front {
+      result.push((*x,*y,*z+1));
+    }  
+    return result;
+  }
+
+  let dims@(back, _, bottom, _, left, _) = dimensions(cubes);
+
+  let mut total = 0;
+  let mut to_visit = vec![(left-1, bottom-1, back-1)];
+  let mut visited  = vec![(left-1, bottom-1, back-1)].into_iter().collect::<HashSet<Cube>>();
+
+  while let Some((x,y,z)) = to_visit.pop() {
+    for neighbor in neighbors(&(x,y,z), &dims) {
+      if cubes.contains(&neighbor) {
+        total += 1;
+      } else if !visited.contains(&neighbor) {
+        visited.insert(neighbor);
+        to_visit.push(neighbor);
+      }
+    }
+  }
+
+  return total.to_string();
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+  type Parsed = HashSet<Cube>;
+
+  fn prepare(lines: &[String]) -> HashSet<Cube> { prepare(lines) }
+  fn one(parsed: &HashSet<Cube>, input: &Input) -> String { one(parsed, input) }
+  fn two(parsed: &HashSet<Cube>, input: &Input) -> String { two(parsed, input) }
+}
@@ -0,0 +1,226 @@
+use common::{Input, Solution};
+use log::trace;
+use std::fmt::Display;
+
+pub type Int = i64;
+
+pub const ORE       : usize = 0;
+pub const CLAY      : usize = 1;
+pub const OBSIDIAN  : usize = 2;
+pub const GEODE     : usize = 3;
+pub const ROCK_COUNT: usize = 4;
+
+pub fn print_rock(rock: usize) -> String {
+  match rock {
+    0 => "Ore".to_string(),
+    1 => "Clay".to_string(),
+    2 => "Obsidian".to_string(),
+    3 => "Geode".to_string(),
+    _ => panic!("Cannot print unknown rock")
+  }
+}
+
+pub struct Blueprint { pub id: Int, pub costs: Vec<Vec<Int>> }
+
+impl Display for Blueprint {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "Blueprint {}:\n{}", self.id, 
+      self.costs.iter().enumerate().fold(
+        String::new(), 
+        |acc, (robot, costs)| {
+          format!("{acc}   {:<8} Robot costs {}\n", print_rock(robot), 
+            costs.iter().enumerate().fold(String::new(), |acc, (rock, cost)| format!("{acc}{}:{cost:<2} ", print_rock(rock)))
+          )
+        }
+      )
+    )
+  }
+}
+
+impl Blueprint {
+  /// The most of each rock that can be spent in one minute. 
+  /// More robots of that rock than this never help, as only one robot is built per minute.
+  pub fn max_spend(&self) -> Vec<Int> {
+    (0..ROCK_COUNT).map(
+      |rock| if rock == GEODE { Int::MAX } else { self.costs.iter().map(|costs| costs[rock]).max().unwrap_or(0) }
+    ).collect()
+  }
+}
+
+#[derive(Clone)]
+pub struct State { pub robots: Vec<Int>, pub ressources: Vec<Int> }
+
+impl State {
+  pub fn new() -> State {
+    State { 
+      robots    : vec![1, 0, 0, 0],
+      ressources: vec![0, 0, 0, 0]
+    }
+  }
+}
+
+impl State {
+  /// Minutes until the robot is built, if it can be built at all with the current robots
+  pub fn minutes_until(&self, robot: usize, blueprint: &Blueprint) -> Option<Int> {
+    blueprint.costs[robot].iter().enumerate().try_fold(
+      0,
+      |wait, (rock, cost)| {
+        let missing = cost - self.ressources[rock];
+        if missing <= 0                { Some(wait) } 
+        else if self.robots[rock] == 0 { None } 
+        else                           { Some(wait.max((missing + self.robots[rock] - 1) / self.robots[rock])) }
+      }
+    ).map(|wait| wait + 1)
+  }
+
+  /// The state after mining for the given minutes and building the robot in the last of them
+  pub fn after(&self, robot: usize, minutes: Int, blueprint: &Blueprint) -> State {
+    let mut next = self.clone();
+    for rock in 0..ROCK_COUNT { 
+      next.ressources[rock] += next.robots[rock]*minutes - blueprint.costs[robot][rock]; 
+    }
+    next.robots[robot] += 1;
+    return next;
+  }
+
+  /// Geodes opened when no more robots are built
+  pub fn idle_geodes(&self, mins_left: Int) -> Int { self.ressources[GEODE] + self.robots[GEODE]*mins_left }
+
+  /// Geodes opened when a geode robot is built in every remaining minute
+  pub fn optimistic_geodes(&self, mins_left: Int) -> Int { self.idle_geodes(mins_left) + mins_left*(mins_left-1)/2 }
+}
+
+impl Display for State {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}# Available: {}", 
+      self.robots.iter().enumerate().fold(String::new(), |acc, (rock, count)| format!("{acc}{} Robots:{count:>2} ", print_rock(rock))),
+      self.ressources.iter().enumerate().fold(String::new(), |acc, (rock, count)| format!("{acc}{}:{count:>2} ", print_rock(rock))),
+    )
+  }
+}
+
+pub fn prepare(lines: &[String]) -> Vec<Blueprint> {
+  lines.iter().map(
+    |line| {
+      let split1: Vec<&str> = line.split("Blueprint ").into_iter().collect();
+      assert_eq!(split1.len(),2);
+      let split2: Vec<&str> = split1[1].split(": Each ore robot costs ").into_iter().collect();
+      assert_eq!(split2.len(),2);
+      let id  = split2[0].parse::<Int>().unwrap();
+      let split3: Vec<&str> = split2[1].split(" ore. Each clay robot costs ").into_iter().collect();
+      assert_eq!(split3.len(),2);
+      let ore_robot_costs = vec![split3[0].parse::<Int>().unwrap()];
+      let split4: Vec<&str> = split3[1].split(" ore. Each obsidian robot costs ").into_iter().collect();
+      assert_eq!(split4.len(),2);
+      let clay_robot_costs = vec![split4[0].parse::<Int>().unwrap()];
+      let split5: Vec<&str> = split4[1].split(" clay. Each geode robot costs ").into_iter().collect();
+      assert_eq!(split5.len(),2);
+      let obsidian_robot_costs: Vec<Int> = split5[0].split(" ore and ").map(|s| s.parse::<Int>().unwrap()).collect();
+      assert_eq!(obsidian_robot_costs.len(),2);
+      let split6: Vec<&str> = split5[1].split(" obsidian.").into_iter().collect();
+      assert_eq!(split6.len(),2);
+      let geode_robot_costs   : Vec<Int> = split6[0].split(" ore and ").map(|s| s.parse::<Int>().unwrap()).collect();
+      assert_eq!(geode_robot_costs.len(),2);
+      Blueprint { 
+        id, 
+        costs: 
+          vec![
+            vec![ore_robot_costs[0]     , 0                      , 0,                    0],
+            vec![clay_robot_costs[0]    , 0                      , 0,                    0],
+            vec![obsidian_robot_costs[0], obsidian_robot_costs[1], 0,                    0],
+            vec![geode_robot_costs[0]   , 0                      , geode_robot_costs[1], 0]
+          ]
+      }
+    }
+  ).collect()
+}
+
+/// The minute a robot is built in, and the robot
+pub type Build = (Int, usize);
+
+/// Branch and bound search over the order in which robots are built
+pub struct Optimizer<'a> { 
+  pub blueprint : &'a Blueprint, 
+  pub max_spend : Vec<Int>, 
+  pub minutes   : Int, 
+  pub best      : Int, 
+  pub best_order: Vec<Build> 
+}
+
+impl Optimizer<'_> {
+  pub fn new(blueprint: &Blueprint, minutes: Int) -> Optimizer<'_> {
+    Optimizer { blueprint, max_spend: blueprint.max_spend(), minutes, best: 0, best_order: vec![] }
+  }
+
+  /// Find the most geodes that can be opened and the build order achieving them
+  pub fn run(mut self) -> (Int, Vec<Build>) {
+    self.search(State::new(), self.minutes, &mut vec![]);
+    return (self.best, self.best_order);
+  }
+
+  /// Instead of deciding minute by minute, decide which robot to build next and skip ahead to it
+  fn search(&mut self, state: State, mins_left: Int, order: &mut Vec<Build>) {
+    let geodes = state.idle_geodes(mins_left);
+    if geodes > self.best {
+      self.best       = geodes;
+      self.best_order = order.clone();
+    }
+    if state.optimistic_geodes(mins_left) <= self.best { return; }
+
+    // Geode robots first, so that good bounds are found early
+    for robot in (0..ROCK_COUNT).rev() {
+      if state.robots[robot] >= self.max_spend[robot] { continue; }
+      if let Some(minutes) = state.minutes_until(robot, self.blueprint) {
+        // A robot built in the last minute does not mine anymore
+        if minutes >= mins_left { continue; }
+        order.push((self.minutes - mins_left + minutes, robot));
+        self.search(state.after(robot, minutes, self.blueprint), mins_left - minutes, order);
+        order.pop();
+      }
+    }
+  }
+}
+
+pub fn print_order(order: &[Build]) -> String {
+  order.iter().map(|(minute, robot)| format!("{minute}:{}", print_rock(*robot))).collect::<Vec<String>>().join(" ")
+}
+
+pub fn max_geodes(blueprint: &Blueprint, minutes: Int) -> Int {
+  let (geodes, order) = Optimizer::new(blueprint, minutes).run();
+  trace!("Blueprint {} opens {geodes} geodes in {minutes} minutes building {}", blueprint.id, print_order(&order));
+  return geodes;
+}
+
+pub fn one(blueprints: &Vec<Blueprint>, _input: &Input) -> String {
+
+  let quality: Int = blueprints.iter().map(
+    |blueprint| {
+      trace!("{blueprint}");
+      blueprint.id*max_geodes(blueprint, 24)
+    }
+  ).sum();
+
+  return quality.to_string();
+}
+
+pub fn two(blueprints: &Vec<Blueprint>, _input: &Input) -> String {
+
+  let result: Int = blueprints.iter().take(3).map(
+    |blueprint| {
+      trace!("{blueprint}");
+      max_geodes(blueprint, 32)
+    }
+  ).product();
+
+  return result.to_string();
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+  type Parsed = Vec<Blueprint>;
+
+  fn prepare(lines: &[String]) -> Vec<Blueprint> { prepare(lines) }
+  fn one(parsed: &Vec<Blueprint>, input: &Input) -> String { one(parsed, input) }
+  fn two(parsed: &Vec<Blueprint>, input: &Input) -> String { two(parsed, input) }
+}
@@ -0,0 +1,90 @@
+use common::{Input, Solution};
+
+pub type Int = i64;
+
+/// A circular list of numbers that are told apart by their original position, as values may repeat. 
+/// `order` holds the original positions in the current order, 
+/// so moving a number only shifts a contiguous block of indices.
+#[derive(Clone)]
+pub struct CircularList {
+  pub values: Vec<Int>,
+  pub order : Vec<usize>
+}
+
+impl CircularList { 
+  pub fn new() -> CircularList { CircularList { values: vec![], order: vec![] } } 
+  pub fn len(&self) -> usize { self.order.len() }
+
+  pub fn insert(&mut self, value: Int, at: usize) {
+    assert!(at <= self.len());
+    self.order.insert(at, self.values.len());
+    self.values.push(value);
+  }
+
+  /// Move the number originally at position `id` forward or backward by its value
+  pub fn move_by_value(&mut self, id: usize) {
+    // A single number has nowhere to go, and the wrap around below would divide by zero
+    if self.len() <= 1 { return; }
+    let from = self.order.iter().position(|other| *other == id).unwrap();
+    self.order.remove(from);
+    // Without the number itself, the list wraps around after one element less
+    let to = (from as Int + self.values[id]).rem_euclid(self.order.len() as Int) as usize;
+    self.order.insert(to, id);
+  }
+
+  /// The same list with every number multiplied by `key`
+  pub fn times(&self, key: Int) -> CircularList {
+    CircularList { values: self.values.iter().map(|value| value*key).collect(), order: self.order.clone() }
+  }
+
+  pub fn get(&self, index: usize) -> Int { self.values[self.order[index]] }
+
+  pub fn index(&self, value: Int) -> usize {
+    self.order.iter().position(|id| self.values[*id] == value).expect("Value is not in the list")
+  }
+}
+
+pub fn prepare(lines: &[String], key: Int) -> CircularList {
+  let mut list = CircularList::new();
+
+  lines.iter().for_each(
+    |line| {
+      list.insert(line.parse::<Int>().unwrap()*key, list.len())
+    }
+  );
+
+  return list;
+}
+
+pub fn decrypt(mut list: CircularList, mixes: usize) -> Int {
+  for _ in 0..mixes {
+    for id in 0..list.len() {
+      list.move_by_value(id);
+    }
+  }
+
+  return vec![1000,2000,3000].into_iter().map(
+    |shift| {
+      list.get((list.index(0) + shift) % list.len())
+    }
+  ).sum();
+}
+
+pub fn one(list: &CircularList, _input: &Input) -> String {
+  return decrypt(list.clone(), 1).to_string();
+}
+
+pub fn two(list: &CircularList, _input: &Input) -> String {
+  let key = 811589153;
+  return decrypt(list.times(key), 10).to_string();
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+  type Parsed = CircularList;
+
+  fn prepare(lines: &[String]) -> CircularList { prepare(lines, 1) }
+  fn one(parsed: &CircularList, input: &Input) -> String { one(parsed, input) }
+  fn two(parsed: &CircularList, input: &Input) -> String { two(parsed, input) }
+}
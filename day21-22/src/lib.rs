@@ -0,0 +1,410 @@
+use common::{Input, Solution};
+use log::info;
+use std::collections::HashMap;
+use std::fmt::Display;
+
+pub type Number = i64;
+
+#[derive(Clone)]
+pub enum Arg { Wait(usize), Ready(Number) }
+
+impl Arg {
+  pub fn is_waiting(&self) -> bool {
+    match self {
+      Arg::Wait(_) => true,
+      _            => false
+    }
+  } 
+
+  pub fn ready(&mut self, other: &Monkey) {
+    assert!(other.has_value());
+    match self {
+      Arg::Wait(id) if *id == other.id => *self = Arg::Ready(other.expr.get_value().unwrap()),
+      _                                => ()
+    }
+  }
+}
+
+impl Display for Arg {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", 
+      match self {
+        Arg::Wait(id)   => format!("m:{id:>4}"),
+        Arg::Ready(val) => format!("val:{val:>4}")
+      }
+    )
+  }
+}
+
+#[derive(Clone)]
+pub enum Expr {  
+  Plus(Arg,Arg),
+  Minus(Arg,Arg),
+  Multiply(Arg,Arg),
+  Divide(Arg,Arg),
+  Value(Number)
+}
+
+impl Expr {
+  pub fn get_value(&self) -> Option<Number> {
+    if let Expr::Value(val) = self {
+      Some(*val)
+    } else {
+      None
+    }
+  }
+}
+
+impl Display for Expr {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", 
+      match self {
+        Expr::Plus(arg1,arg2)     => format!("{arg1}+{arg2}"),
+        Expr::Minus(arg1,arg2)    => format!("{arg1}-{arg2}"),
+        Expr::Multiply(arg1,arg2) => format!("{arg1}*{arg2}"),
+        Expr::Divide(arg1,arg2)   => format!("{arg1}÷{arg2}"),
+        Expr::Value(val)          => format!("{val}")
+      }
+    )
+  }
+}
+
+#[derive(Clone)]
+pub struct Monkey { pub id: usize, pub name: String, pub expr: Expr }
+
+impl Monkey {
+  pub fn is_root(&self, root_id: usize) -> bool { self.id == root_id }
+  pub fn has_value(&self) -> bool { if let Expr::Value(_) = self.expr { true } else { false } }
+
+  pub fn listen(&mut self, other: &Monkey) {
+    assert!(self.waits());
+    assert!(!self.has_value());
+    assert!(other.has_value());
+    let new_expr = match self.expr.clone() {
+      Expr::Plus(mut arg1,mut arg2)     => {
+        arg1.ready(other);
+        arg2.ready(other);
+        Expr::Plus(arg1, arg2)
+      },
+      Expr::Minus(mut arg1,mut arg2)    => {
+        arg1.ready(other);
+        arg2.ready(other);
+        Expr::Minus(arg1, arg2)
+      },
+      Expr::Multiply(mut arg1,mut arg2) => {
+        arg1.ready(other);
+        arg2.ready(other);
+        Expr::Multiply(arg1, arg2)
+      },
+      Expr::Divide(mut arg1,mut arg2)   => {
+        arg1.ready(other);
+        arg2.ready(other);
+        Expr::Divide(arg1, arg2)
+      },
+      Expr::Value(_)                    => panic!("Monkey tried to listen, but was actually screaming")
+    };
+    self.expr = new_expr;
+  }
+
+  pub fn waits(&self) -> bool {
+    match &self.expr {
+      Expr::Plus(arg1,arg2)     => arg1.is_waiting() || arg2.is_waiting(), 
+      Expr::Minus(arg1,arg2)    => arg1.is_waiting() || arg2.is_waiting(),
+      Expr::Multiply(arg1,arg2) => arg1.is_waiting() || arg2.is_waiting(),
+      Expr::Divide(arg1,arg2)   => arg1.is_waiting() || arg2.is_waiting(),
+      Expr::Value(_)            => false 
+    }
+  }
+
+  pub fn eval(&mut self) {
+    assert!(!self.waits() && !self.has_value());
+    let new_expr = Expr::Value(match self.expr.clone() {
+      Expr::Plus(Arg::Ready(v1),Arg::Ready(v2))     => v1+v2,
+      Expr::Minus(Arg::Ready(v1),Arg::Ready(v2))    => v1-v2,
+      Expr::Multiply(Arg::Ready(v1),Arg::Ready(v2)) => v1*v2,
+      Expr::Divide(Arg::Ready(v1),Arg::Ready(v2))   => v1/v2,
+      _                                             => panic!("Monkey cannot do arithmetic while still waiting")
+    });
+    self.expr = new_expr;
+  }
+}
+
+impl Display for Monkey {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}: {}", self.name, self.expr)
+  }
+}
+
+pub fn prepare(lines: &[String]) -> (Vec<Monkey>, HashMap<usize, Vec<usize>>, Option<usize>) {
+  let mut monkey_do = vec![];
+  let monkey_map: HashMap<String, usize> = lines.iter().enumerate().map(
+    |(id,line)| {
+      let split: Vec<&str> = line.split(": ").collect();
+      assert_eq!(split.len(),2);
+      monkey_do.push((split[0].to_string(), split[1].to_string()));
+      (split[0].to_string(),id)
+    }
+  ).collect();
+  let mut dependencies: HashMap<usize, Vec<usize>> = HashMap::new();
+  let mut root_id_option = None;
+  let monkeys = monkey_do.into_iter().enumerate().map(
+    |(id, (name, mdo))| {
+      if name == "root".to_string() { root_id_option = Some(id); }
+      let split: Vec<&str> = mdo.split_whitespace().collect();
+      Monkey { 
+        id,
+        name,
+        expr: if split.len() == 1 {
+                Expr::Value(split[0].parse::<Number>().unwrap())
+              } else if split.len() == 3 {
+                let (m1,m2) = (monkey_map[split[0]], monkey_map[split[2]]);
+                if !dependencies.contains_key(&m1) { dependencies.insert(m1, vec![]); }
+                if !dependencies.contains_key(&m2) { dependencies.insert(m2, vec![]); }
+                dependencies.get_mut(&m1).unwrap().push(id);
+                dependencies.get_mut(&m2).unwrap().push(id);
+                match split[1] {
+                  "+" => Expr::Plus(Arg::Wait(m1), Arg::Wait(m2)), 
+                  "-" => Expr::Minus(Arg::Wait(m1), Arg::Wait(m2)), 
+                  "*" => Expr::Multiply(Arg::Wait(m1), Arg::Wait(m2)), 
+                  "/" => Expr::Divide(Arg::Wait(m1), Arg::Wait(m2)),
+                   _  => panic!("Invalid operator found")
+                }
+              } else {
+                panic!("malformed monkey")
+              }
+      }
+    }
+  ).collect();
+  return (monkeys, dependencies, root_id_option);
+}
+
+pub fn screaming_monkeys(root_id: usize, monkeys: &mut Vec<Monkey>, dependencies: &mut HashMap<usize, Vec<usize>>) -> Number {
+  let mut ready_monkeys: Vec<Monkey> = vec![];
+  monkeys.iter().for_each(
+    |monkey| match monkey.expr {
+      Expr::Value(_) => ready_monkeys.push(monkey.clone()),
+      _              => ()
+    }
+  );
+
+  while !ready_monkeys.is_empty() {
+    let screaming_monkey = ready_monkeys.pop().unwrap();
+    if screaming_monkey.is_root(root_id) { break; }
+    dependencies.remove(&screaming_monkey.id).unwrap().into_iter().for_each(
+      |id| {
+        let other_monkey = monkeys.get_mut(id).unwrap();
+        other_monkey.listen(&screaming_monkey);
+        if !other_monkey.waits() {
+          other_monkey.eval();
+          ready_monkeys.push(other_monkey.clone());
+        }
+      }
+    );
+  }
+  assert!(ready_monkeys.is_empty());
+  return if let Expr::Value(val) = monkeys[root_id].expr { val } else { panic!("wait, root monkey was not ready") };
+}
+
+pub const HUMAN: &str = "humn";
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Operator { Plus, Minus, Multiply, Divide }
+
+impl Operator {
+  pub fn apply(&self, v1: Number, v2: Number) -> Number {
+    match self {
+      Operator::Plus     => v1+v2,
+      Operator::Minus    => v1-v2,
+      Operator::Multiply => v1*v2,
+      Operator::Divide   => v1/v2
+    }
+  }
+}
+
+impl Display for Operator {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", 
+      match self {
+        Operator::Plus     => "+",
+        Operator::Minus    => "-",
+        Operator::Multiply => "*",
+        Operator::Divide   => "÷"
+      }
+    )
+  }
+}
+
+/// What the monkeys compute, with every branch not depending on the human already evaluated
+pub enum Tree { Human, Value(Number), Operation(Operator, Box<Tree>, Box<Tree>) }
+
+impl Tree {
+  pub fn new(id: usize, monkeys: &Vec<Monkey>) -> Tree {
+    let monkey = &monkeys[id];
+    if monkey.name == HUMAN { return Tree::Human; }
+
+    let (operator, arg1, arg2) = match &monkey.expr {
+      Expr::Value(val)          => return Tree::Value(*val),
+      Expr::Plus(arg1,arg2)     => (Operator::Plus, arg1, arg2),
+      Expr::Minus(arg1,arg2)    => (Operator::Minus, arg1, arg2),
+      Expr::Multiply(arg1,arg2) => (Operator::Multiply, arg1, arg2),
+      Expr::Divide(arg1,arg2)   => (Operator::Divide, arg1, arg2)
+    };
+    let branch = |arg: &Arg| match arg {
+      Arg::Wait(id)   => Tree::new(*id, monkeys),
+      Arg::Ready(val) => Tree::Value(*val)
+    };
+
+    match (branch(arg1), branch(arg2)) {
+      (Tree::Value(v1), Tree::Value(v2)) => Tree::Value(operator.apply(v1, v2)),
+      (tree1, tree2)                     => Tree::Operation(operator, Box::new(tree1), Box::new(tree2))
+    }
+  }
+
+  pub fn has_human(&self) -> bool { !matches!(self, Tree::Value(_)) }
+
+  pub fn eval(&self, human: Number) -> Number {
+    match self {
+      Tree::Human                          => human,
+      Tree::Value(val)                     => *val,
+      Tree::Operation(operator,tree1,tree2) => operator.apply(tree1.eval(human), tree2.eval(human))
+    }
+  }
+
+  /// Find the value of the human, such that the tree evaluates to the target, 
+  /// by undoing the operations on the path from the top down to the human
+  pub fn solve(&self, target: Number) -> Result<Number, String> {
+    let (operator, tree1, tree2) = match self {
+      Tree::Human                          => return Ok(target),
+      Tree::Value(_)                       => return Err(format!("{HUMAN} is not needed, so any value works")),
+      Tree::Operation(operator,tree1,tree2) => (*operator, tree1, tree2)
+    };
+
+    match (operator, tree1.as_ref(), tree2.as_ref()) {
+      (_, Tree::Value(_), Tree::Value(_))         => panic!("Constant operation was not evaluated"),
+      (_, tree1, tree2) if tree1.has_human() && tree2.has_human() => 
+        Err(format!("{HUMAN} appears on both sides of {operator}")),
+      (Operator::Plus    , tree, Tree::Value(v)) | 
+      (Operator::Plus    , Tree::Value(v), tree) => tree.solve(target - v),
+      (Operator::Minus   , tree, Tree::Value(v)) => tree.solve(target + v),
+      (Operator::Minus   , Tree::Value(v), tree) => tree.solve(v - target),
+      (Operator::Multiply, tree, Tree::Value(v)) | 
+      (Operator::Multiply, Tree::Value(v), tree) => {
+        if *v == 0 && target == 0         { Err("Multiplied by 0, so any value works".to_string()) }
+        else if *v == 0                   { Err(format!("Multiplied by 0, so {target} is never reached")) }
+        else if target % v != 0           { Err(format!("{target} is not a multiple of {v}, so {HUMAN} is not an integer")) }
+        else                              { tree.solve(target / v) }
+      },
+      (Operator::Divide  , tree, Tree::Value(v)) => {
+        if *v == 0                        { Err("Divided by 0".to_string()) }
+        else                              { tree.solve(target * v) }
+      },
+      (Operator::Divide  , Tree::Value(v), tree) => {
+        if target == 0 && *v == 0         { Err("0 divided by anything is 0, so any value works".to_string()) }
+        else if target == 0 || v % target != 0 { Err(format!("{v} is not a multiple of {target}, so {HUMAN} is not an integer")) }
+        else                              { tree.solve(v / target) }
+      },
+      _                                   => unreachable!()
+    }
+  }
+
+  /// The tree as `(a*humn + b) ÷ d`, if it is linear in the human
+  pub fn linear(&self) -> Option<Linear> {
+    match self {
+      Tree::Human                          => Some(Linear::new(1, 0, 1)),
+      Tree::Value(val)                     => Some(Linear::new(0, *val as i128, 1)),
+      Tree::Operation(operator,tree1,tree2) => {
+        let (l1, l2) = (tree1.linear()?, tree2.linear()?);
+        match operator {
+          Operator::Plus                     => Some(Linear::new(l1.a*l2.d + l2.a*l1.d, l1.b*l2.d + l2.b*l1.d, l1.d*l2.d)),
+          Operator::Minus                    => Some(Linear::new(l1.a*l2.d - l2.a*l1.d, l1.b*l2.d - l2.b*l1.d, l1.d*l2.d)),
+          Operator::Multiply if l1.a == 0    => Some(Linear::new(l2.a*l1.b, l2.b*l1.b, l1.d*l2.d)),
+          Operator::Multiply if l2.a == 0    => Some(Linear::new(l1.a*l2.b, l1.b*l2.b, l1.d*l2.d)),
+          Operator::Divide if l2.a == 0 && l2.b != 0 => Some(Linear::new(l1.a*l2.d, l1.b*l2.d, l1.d*l2.b)),
+          _                                  => None
+        }
+      }
+    }
+  }
+}
+
+impl Display for Tree {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Tree::Human                          => write!(f, "{HUMAN}"),
+      Tree::Value(val)                     => write!(f, "{val}"),
+      Tree::Operation(operator,tree1,tree2) => write!(f, "({tree1} {operator} {tree2})")
+    }
+  }
+}
+
+/// `(a*humn + b) ÷ d` with the fraction reduced
+pub struct Linear { pub a: i128, pub b: i128, pub d: i128 }
+
+impl Linear {
+  pub fn new(a: i128, b: i128, d: i128) -> Linear {
+    fn gcd(a: i128, b: i128) -> i128 { if b == 0 { a.abs() } else { gcd(b, a % b) } }
+    let divisor = gcd(gcd(a, b), d) * d.signum();
+    Linear { a: a/divisor, b: b/divisor, d: d/divisor }
+  }
+}
+
+impl Display for Linear {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let product = match self.a {
+      1  => HUMAN.to_string(),
+      -1 => format!("-{HUMAN}"),
+      a  => format!("{a}*{HUMAN}")
+    };
+    let sum = match self.b {
+      0          => product,
+      b if b < 0 => format!("{product} - {}", -b),
+      b          => format!("{product} + {b}")
+    };
+    if self.d == 1 { write!(f, "{sum}") } else { write!(f, "({sum}) ÷ {}", self.d) }
+  }
+}
+
+pub fn one((monkeys, dependencies, root_id_option): &(Vec<Monkey>, HashMap<usize, Vec<usize>>, Option<usize>), _input: &Input) -> String {
+  let (mut monkeys, mut dependencies) = (monkeys.clone(), dependencies.clone());
+  let root_id = root_id_option.unwrap();
+  return screaming_monkeys(root_id, &mut monkeys, &mut dependencies).to_string();
+}
+
+pub fn two((monkeys, _, root_id_option): &(Vec<Monkey>, HashMap<usize, Vec<usize>>, Option<usize>), input: &Input) -> String {
+  let root_id = root_id_option.unwrap();
+
+  let (human_branch, target) = match Tree::new(root_id, monkeys) {
+    Tree::Operation(_, tree, value) | 
+    Tree::Operation(_, value, tree) if !value.has_human() => match *value {
+      Tree::Value(val) => (*tree, val),
+      _                => unreachable!()
+    },
+    Tree::Operation(_, _, _)                              => panic!("{HUMAN} appears on both sides of root"),
+    _                                                     => panic!("root monkey does not compare two monkeys")
+  };
+
+  if input.flag("explain") {
+    info!("Equation: {human_branch} = {target}");
+    match human_branch.linear() {
+      Some(linear) => info!("Simplified: {linear} = {target}"),
+      None         => info!("Simplified: not linear in {HUMAN}")
+    }
+  }
+
+  return match human_branch.solve(target) {
+    // Undoing a division assumes it was exact, which the monkeys do not check
+    Ok(human) if human_branch.eval(human) != target => panic!("No integer solution: {human} does not divide evenly"),
+    Ok(human)                                       => human.to_string(),
+    Err(err)                                        => panic!("No unique integer solution: {err}")
+  };
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+  type Parsed = (Vec<Monkey>, HashMap<usize, Vec<usize>>, Option<usize>);
+
+  fn prepare(lines: &[String]) -> (Vec<Monkey>, HashMap<usize, Vec<usize>>, Option<usize>) { prepare(lines) }
+  fn one(parsed: &(Vec<Monkey>, HashMap<usize, Vec<usize>>, Option<usize>), input: &Input) -> String { one(parsed, input) }
+  fn two(parsed: &(Vec<Monkey>, HashMap<usize, Vec<usize>>, Option<usize>), input: &Input) -> String { two(parsed, input) }
+}
@@ -0,0 +1,381 @@
+use common::{Input, Solution};
+use log::info;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+pub type Pos  = (usize,usize);
+pub type Dims = (Pos,Pos);
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Direction { N, S, E, W }
+
+impl Direction {
+  pub const ALL: [Direction; 4] = [Direction::N, Direction::S, Direction::E, Direction::W];
+
+  /// The position of the direction in `ALL`
+  pub fn index(&self) -> usize {
+    match self { Direction::N => 0, Direction::S => 1, Direction::E => 2, Direction::W => 3 }
+  }
+
+  pub fn right(&self) -> Direction {
+    match self { Direction::N => Direction::E, Direction::E => Direction::S, Direction::S => Direction::W, Direction::W => Direction::N }
+  }
+
+  pub fn left(&self) -> Direction {
+    match self { Direction::N => Direction::W, Direction::W => Direction::S, Direction::S => Direction::E, Direction::E => Direction::N }
+  }
+
+  pub fn opposite(&self) -> Direction { self.right().right() }
+
+  pub fn score(&self) -> usize {
+    match self { Direction::E => 0, Direction::S => 1, Direction::W => 2, Direction::N => 3 }
+  }
+
+  pub fn arrow(&self) -> char {
+    match self { Direction::N => '^', Direction::S => 'v', Direction::E => '>', Direction::W => '<' }
+  }
+}
+
+/// Leaving a room over one of its sides leads to room `to`, entering over its side `side`.
+/// The offsets along the side that is left map onto `range.0` to `range.1`, which is reversed if the side is flipped.
+#[derive(Clone)]
+pub struct Transition { pub to: usize, pub side: Direction, pub range: (usize,usize) }
+
+/// A square block of the map with its walls,
+/// and the transitions over its sides in the order of `Direction::ALL`
+#[derive(Clone)]
+pub struct Room { pub id: usize, pub dimensions: Dims, pub transitions: Vec<Transition>, pub walls: HashSet<Pos> }
+
+impl Room {
+  pub fn size(&self) -> usize { self.dimensions.1.0 - self.dimensions.0.0 + 1 }
+
+  pub fn contains(&self, (x,y): Pos) -> bool {
+    let ((x0,y0),(x1,y1)) = self.dimensions;
+    x0 <= x && x <= x1 && y0 <= y && y <= y1
+  }
+
+  /// The block of the room, when the map is divided into blocks of the size of a room
+  pub fn block(&self) -> Pos { (self.dimensions.0.0 / self.size(), self.dimensions.0.1 / self.size()) }
+
+  /// The offset of a position along a side, counted from west to east or from north to south
+  pub fn offset(&self, (x,y): Pos, side: Direction) -> usize {
+    let ((x0,y0),_) = self.dimensions;
+    match side {
+      Direction::N | Direction::S => x - x0,
+      Direction::E | Direction::W => y - y0
+    }
+  }
+
+  /// The position at an offset along a side
+  pub fn on_side(&self, side: Direction, offset: usize) -> Pos {
+    let ((x0,y0),(x1,y1)) = self.dimensions;
+    match side {
+      Direction::N => (x0+offset, y0),
+      Direction::S => (x0+offset, y1),
+      Direction::W => (x0, y0+offset),
+      Direction::E => (x1, y0+offset)
+    }
+  }
+
+  /// The next position in the room, if the step does not leave it
+  pub fn step(&self, (x,y): Pos, facing: Direction) -> Option<Pos> {
+    let next = match facing {
+      Direction::N => (x, y.checked_sub(1)?),
+      Direction::S => (x, y+1),
+      Direction::E => (x+1, y),
+      Direction::W => (x.checked_sub(1)?, y)
+    };
+    if self.contains(next) { Some(next) } else { None }
+  }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Instruction { L, R, Walk(usize) }
+
+#[derive(Clone, Copy, Debug)]
+pub struct State { pub room: usize, pub position: Pos, pub facing: Direction }
+
+impl State {
+  /// Walk as far as the walls allow, and remember the path in the trace
+  pub fn walk(&mut self, steps: usize, rooms: &[Room], trace: &mut HashMap<Pos,Direction>) {
+    for _ in 0..steps {
+      let room = &rooms[self.room];
+      let (next_room, next_position, next_facing) = match room.step(self.position, self.facing) {
+        Some(position) => (self.room, position, self.facing),
+        None           => {
+          let transition = &room.transitions[self.facing.index()];
+          let offset     = room.offset(self.position, self.facing);
+          let offset     = if transition.range.0 <= transition.range.1 { transition.range.0 + offset } else { transition.range.0 - offset };
+          (transition.to, rooms[transition.to].on_side(transition.side, offset), transition.side.opposite())
+        }
+      };
+      if rooms[next_room].walls.contains(&next_position) { break; }
+
+      (self.room, self.position, self.facing) = (next_room, next_position, next_facing);
+      trace.insert(self.position, self.facing);
+    }
+  }
+
+  pub fn execute(&mut self, instruction: &Instruction, rooms: &[Room], trace: &mut HashMap<Pos,Direction>) {
+    match instruction {
+      Instruction::L           => self.facing = self.facing.left(),
+      Instruction::R           => self.facing = self.facing.right(),
+      Instruction::Walk(steps) => self.walk(*steps, rooms, trace)
+    }
+    trace.insert(self.position, self.facing);
+  }
+
+  pub fn password(&self) -> usize { 1000*(self.position.1+1) + 4*(self.position.0+1) + self.facing.score() }
+}
+
+fn gcd(a: usize, b: usize) -> usize { if b == 0 { a } else { gcd(b, a % b) } }
+
+/// The largest block size, such that every block of the map is either completely open or completely empty
+pub fn room_size(map_strings: &[String]) -> usize {
+  let tile = |x: usize, y: usize| map_strings.get(y).and_then(|line| line.chars().nth(x)).map_or(false, |c| c != ' ');
+  let width = map_strings.iter().map(|line| line.len()).max().unwrap_or(0);
+
+  let rows = map_strings.iter().fold(
+    map_strings.len(),
+    |acc, line| gcd(gcd(acc, line.len() - line.trim_start().len()), line.trim_end().len())
+  );
+  (0..width).fold(
+    gcd(rows, width),
+    |acc, x| {
+      let first = (0..map_strings.len()).find(|y| tile(x,*y)).unwrap_or(0);
+      let end   = (0..map_strings.len()).rev().find(|y| tile(x,*y)).map_or(0, |y| y+1);
+      gcd(gcd(acc, first), end)
+    }
+  )
+}
+
+/// Divide the map into rooms of equal size, numbered from the top left to the bottom right
+pub fn to_rooms(map_strings: &[String]) -> Vec<Room> {
+  let size  = room_size(map_strings);
+  let chars: Vec<Vec<char>> = map_strings.iter().map(|line| line.chars().collect()).collect();
+  let width = chars.iter().map(|line| line.len()).max().unwrap_or(0);
+  let mut rooms = vec![];
+
+  for y0 in (0..chars.len()).step_by(size) {
+    for x0 in (0..width).step_by(size) {
+      if chars[y0].get(x0).map_or(true, |c| *c == ' ') { continue; }
+      let walls = (y0..y0+size).flat_map(|y| (x0..x0+size).map(move |x| (x,y))).filter(|(x,y)| chars[*y][*x] == '#').collect();
+      rooms.push(Room { id: rooms.len(), dimensions: ((x0,y0),(x0+size-1,y0+size-1)), transitions: vec![], walls });
+    }
+  }
+
+  return rooms;
+}
+
+/// Leaving the map on one side continues on the opposite side of the same row or column
+pub fn connect_flat(rooms: &mut Vec<Room>) {
+  let blocks: HashMap<Pos,usize> = rooms.iter().map(|room| (room.block(), room.id)).collect();
+  let columns = blocks.keys().map(|(x,_)| x+1).max().unwrap_or(0);
+  let rows    = blocks.keys().map(|(_,y)| y+1).max().unwrap_or(0);
+
+  rooms.iter_mut().for_each(
+    |room| {
+      let (x,y) = room.block();
+      let size  = room.size();
+      room.transitions = Direction::ALL.iter().map(
+        |exit| {
+          let to = (1..).map(
+            |k| match exit {
+              Direction::N => (x, (y + rows - k % rows) % rows),
+              Direction::S => (x, (y + k) % rows),
+              Direction::E => ((x + k) % columns, y),
+              Direction::W => ((x + columns - k % columns) % columns, y)
+            }
+          ).find_map(|block| blocks.get(&block)).unwrap();
+          Transition { to: *to, side: exit.opposite(), range: (0, size-1) }
+        }
+      ).collect();
+    }
+  );
+}
+
+pub type Vector = [i64; 3];
+
+fn neg(v: Vector) -> Vector { [-v[0], -v[1], -v[2]] }
+fn add(v: Vector, w: Vector) -> Vector { [v[0]+w[0], v[1]+w[1], v[2]+w[2]] }
+
+/// How a room lies on the cube: where its east, its south and its outside point to
+#[derive(Clone, Copy)]
+pub struct Frame { pub right: Vector, pub down: Vector, pub normal: Vector }
+
+impl Frame {
+  /// The frame of the neighbouring room in the net, after folding it along the shared side
+  pub fn fold(&self, exit: Direction) -> Frame {
+    let Frame { right, down, normal } = *self;
+    match exit {
+      Direction::N => Frame { right, down: normal, normal: neg(down) },
+      Direction::S => Frame { right, down: neg(normal), normal: down },
+      Direction::E => Frame { right: neg(normal), down, normal: right },
+      Direction::W => Frame { right: normal, down, normal: neg(right) }
+    }
+  }
+
+  /// Where leaving over the side leads to, which is the normal of the room behind it
+  pub fn towards(&self, side: Direction) -> Vector {
+    match side {
+      Direction::N => neg(self.down),
+      Direction::S => self.down,
+      Direction::E => self.right,
+      Direction::W => neg(self.right)
+    }
+  }
+
+  /// The corners of a side, at offset 0 and at the last offset, on a cube with corners at ±1
+  pub fn corners(&self, side: Direction) -> (Vector, Vector) {
+    let center = add(self.normal, self.towards(side));
+    let along  = match side {
+      Direction::N | Direction::S => self.right,
+      Direction::E | Direction::W => self.down
+    };
+    (add(center, neg(along)), add(center, along))
+  }
+}
+
+/// Fold the map into a cube, which works for all 11 nets of a cube:
+/// The first room is the top of the cube, and every room reached from it in the net is folded down along the shared side.
+/// Sides meet where their corners on the cube do, and are flipped if the corners are swapped.
+pub fn connect_cube(rooms: &mut Vec<Room>) {
+  assert_eq!(rooms.len(), 6, "A cube has 6 faces, but the map has {} rooms", rooms.len());
+  let blocks: HashMap<Pos,usize> = rooms.iter().map(|room| (room.block(), room.id)).collect();
+
+  let mut frames: Vec<Option<Frame>> = vec![None; rooms.len()];
+  frames[0] = Some(Frame { right: [1,0,0], down: [0,1,0], normal: [0,0,1] });
+  let mut to_visit = VecDeque::from([0]);
+  while let Some(id) = to_visit.pop_front() {
+    let frame = frames[id].unwrap();
+    let (x,y) = rooms[id].block();
+    Direction::ALL.iter().for_each(
+      |exit| {
+        let neighbor = match exit {
+          Direction::N => y.checked_sub(1).map(|y| (x,y)),
+          Direction::S => Some((x,y+1)),
+          Direction::E => Some((x+1,y)),
+          Direction::W => x.checked_sub(1).map(|x| (x,y))
+        }.and_then(|block| blocks.get(&block));
+        if let Some(&neighbor) = neighbor {
+          if frames[neighbor].is_none() {
+            frames[neighbor] = Some(frame.fold(*exit));
+            to_visit.push_back(neighbor);
+          }
+        }
+      }
+    );
+  }
+  let frames: Vec<Frame> = frames.into_iter().map(|frame| frame.expect("The rooms are not connected")).collect();
+
+  let size = rooms[0].size();
+  rooms.iter_mut().for_each(
+    |room| {
+      let frame = frames[room.id];
+      room.transitions = Direction::ALL.iter().map(
+        |exit| {
+          let corners = frame.corners(*exit);
+          let to      = frames.iter().position(|other| other.normal == frame.towards(*exit)).expect("The net does not fold into a cube");
+          Direction::ALL.iter().find_map(
+            |side| {
+              let (first, last) = frames[to].corners(*side);
+              if (first, last) == corners      { Some(Transition { to, side: *side, range: (0, size-1) }) }
+              else if (last, first) == corners { Some(Transition { to, side: *side, range: (size-1, 0) }) }
+              else                             { None }
+            }
+          ).unwrap()
+        }
+      ).collect();
+    }
+  );
+}
+
+pub fn prepare(lines: &[String]) -> (Vec<Room>, VecDeque<Instruction>, State) {
+  let rest: Vec<&[String]> = lines.split(|line| line.is_empty()).collect();
+  assert_eq!(rest.len(),2);
+  let (map_strings, inst_string) = (rest[0], rest[1]);
+
+  let rooms = to_rooms(map_strings);
+  let start = (map_strings[0].find('.').expect("No open tile in the top row"), 0);
+  let state = State { room: rooms.iter().position(|room| room.contains(start)).unwrap(), position: start, facing: Direction::E };
+
+  assert_eq!(inst_string.len(), 1);
+  let mut instructions: VecDeque<Instruction> = VecDeque::new();
+  let last = inst_string[0].chars().fold(
+    String::new(),
+    |mut acc, c| {
+      match c {
+        '0'|'1'|'2'|'3'|'4'|'5'|'6'|'7'|'8'|'9' => acc.push(c),
+        'L'                                     => {
+          instructions.push_back(Instruction::Walk(acc.parse::<usize>().unwrap()));
+          instructions.push_back(Instruction::L);
+          acc.clear();
+        },
+        'R'                                     => {
+          instructions.push_back(Instruction::Walk(acc.parse::<usize>().unwrap()));
+          instructions.push_back(Instruction::R);
+          acc.clear();
+        },
+         _                                      => panic!("Malformed instruction input")
+      }
+      acc
+    }
+  );
+  if !last.is_empty() {
+    instructions.push_back(Instruction::Walk(last.parse::<usize>().unwrap()));
+  }
+
+  return (rooms, instructions, state);
+}
+
+/// The map with the path drawn as arrows
+pub fn render(rooms: &[Room], trace: &HashMap<Pos,Direction>) -> String {
+  let width  = rooms.iter().map(|room| room.dimensions.1.0+1).max().unwrap_or(0);
+  let height = rooms.iter().map(|room| room.dimensions.1.1+1).max().unwrap_or(0);
+
+  (0..height).map(
+    |y| (0..width).map(
+      |x| match (trace.get(&(x,y)), rooms.iter().find(|room| room.contains((x,y)))) {
+        (Some(facing), _)                                 => facing.arrow(),
+        (None, Some(room)) if room.walls.contains(&(x,y)) => '#',
+        (None, Some(_))                                   => '.',
+        (None, None)                                      => ' '
+      }
+    ).collect::<String>().trim_end().to_string()
+  ).collect::<Vec<String>>().join("\n")
+}
+
+/// Follow the instructions and print the path, if `--render` is given
+pub fn follow(input: &Input, rooms: &[Room], instructions: &VecDeque<Instruction>, mut state: State) -> State {
+  let mut trace = HashMap::from([(state.position, state.facing)]);
+  instructions.iter().for_each(|instruction| state.execute(instruction, rooms, &mut trace));
+
+  if input.flag("render") {
+    info!("Path:\n{}", render(rooms, &trace));
+  }
+
+  return state;
+}
+
+pub fn one((rooms, instructions, state): &(Vec<Room>, VecDeque<Instruction>, State), input: &Input) -> String {
+  let mut rooms = rooms.clone();
+  connect_flat(&mut rooms);
+
+  return follow(input, &rooms, instructions, *state).password().to_string();
+}
+
+pub fn two((rooms, instructions, state): &(Vec<Room>, VecDeque<Instruction>, State), input: &Input) -> String {
+  let mut rooms = rooms.clone();
+  connect_cube(&mut rooms);
+
+  return follow(input, &rooms, instructions, *state).password().to_string();
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+  type Parsed = (Vec<Room>, VecDeque<Instruction>, State);
+
+  fn prepare(lines: &[String]) -> (Vec<Room>, VecDeque<Instruction>, State) { prepare(lines) }
+  fn one(parsed: &(Vec<Room>, VecDeque<Instruction>, State), input: &Input) -> String { one(parsed, input) }
+  fn two(parsed: &(Vec<Room>, VecDeque<Instruction>, State), input: &Input) -> String { two(parsed, input) }
+}
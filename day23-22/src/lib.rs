@@ -0,0 +1,175 @@
+use common::{Input, Solution};
+use log::trace;
+use std::collections::{HashMap, HashSet};
+
+pub type Int = i64;
+pub type Pos = (Int,Int);
+
+#[derive(Clone)]
+pub enum Dir { N, S, W, E }
+
+impl Dir {
+  pub fn delta(&self) -> [Pos; 3] {
+    match self {
+      Dir::N => [(-1,-1),(0,-1),( 1,-1)],
+      Dir::S => [(-1, 1),(0, 1),( 1, 1)],
+      Dir::W => [(-1,-1),(-1,0),(-1, 1)],
+      Dir::E => [( 1,-1),( 1,0),( 1, 1)]
+    }
+  }
+  pub fn go(&self, (x,y): Pos) -> Pos {
+    match self {
+      Dir::N => (x,y-1),
+      Dir::S => (x,y+1),
+      Dir::W => (x-1,y),
+      Dir::E => (x+1,y)
+    }
+  }
+}
+
+#[derive(Clone)]
+pub struct Elf { pub next: Vec<Dir> }
+
+impl Elf {
+  pub fn new() -> Elf { Elf { next: vec![Dir::N,Dir::S,Dir::W,Dir::E] } }
+
+  pub fn propose(&mut self, pos@(x,y): Pos, occupied: &HashSet<Pos>) -> Option<Pos> {
+    let (proposal_pos_option, has_neighbor) = self.next.iter().enumerate().fold(
+      (None, false), 
+      |(proposal_pos_option, has_neighbor), (i,dir)| {
+        if dir.delta().iter().all(|(dx,dy)| { !occupied.contains(&(x+dx,y+dy)) }) {
+          if proposal_pos_option.is_none() {
+            (Some(i), has_neighbor)
+          } else {
+            (proposal_pos_option, has_neighbor)
+          }
+        } else {
+          (proposal_pos_option, true)
+        }
+      }
+    );
+    let proposal_option = proposal_pos_option.map(|proposal_pos| self.next[proposal_pos].go(pos));
+    let dir = self.next.remove(0);
+    self.next.push(dir);
+    return if has_neighbor {
+      proposal_option
+    } else {
+      None
+    }
+  }
+}
+
+pub fn prepare(lines: &[String]) -> HashMap<Pos, Elf> {
+  let mut elves = HashMap::new();
+  lines.iter().enumerate().for_each(
+    |(y,line)| {
+      line.chars().enumerate().for_each(
+        |(x,c)| {
+          match c {
+            '.' => (),
+            '#' => { elves.insert((x as Int,y as Int), Elf::new()); },
+             _  => panic!("Malformed input")
+          };
+        }
+      )
+    }
+  );
+  return elves;
+}
+
+pub fn dims(elves: &HashMap<Pos,Elf>) -> (Int,Int,Int,Int) {
+  (*elves.keys().map(|(x,_)| x).min_by(|x1,x2| x1.cmp(&x2)).unwrap(),  
+   *elves.keys().map(|(x,_)| x).max_by(|x1,x2| x1.cmp(&x2)).unwrap(),
+   *elves.keys().map(|(_,y)| y).min_by(|y1,y2| y1.cmp(&y2)).unwrap(),  
+   *elves.keys().map(|(_,y)| y).max_by(|y1,y2| y1.cmp(&y2)).unwrap())
+}
+
+pub fn trace_board(elves: &HashMap<Pos,Elf>) {
+  let (min_x,max_x,min_y,max_y) = dims(&elves);
+
+  trace!("{}", (min_y..=max_y).fold(
+    String::new(), 
+    |acc, y| {
+      format!("{acc}\n{}",
+        (min_x..=max_x).fold( 
+          String::new(),
+          |acc, x| {
+            format!("{acc}{}", 
+              if elves.contains_key(&(x,y)) {
+                '#'
+              } else {
+                '.'
+              }
+            )
+          }
+        )
+      )
+    }
+  ));
+}
+
+pub fn scatter(mut elves: HashMap<Pos, Elf>, max_round_option: Option<Int>) -> (Int, Int) {
+  let mut round = 0;
+  let mut proposed = false;
+  let mut proposals: HashMap<Pos, Pos> = HashMap::new();
+
+  while (max_round_option.is_none() || round < max_round_option.unwrap()) && (!proposed || !proposals.is_empty()) {
+    proposals.clear();
+
+    //trace_board(&elves);
+
+    let occupied: HashSet<Pos> = elves.keys().map(|pos| *pos).collect();
+    let mut clash: HashSet<Pos> = HashSet::new();
+    elves.iter_mut().for_each(
+      |(pos, elf)| {
+        let proposed_pos_option = elf.propose(*pos, &occupied); // Also moves proposals
+        if let Some(proposed_pos) = proposed_pos_option {
+          if !clash.contains(&proposed_pos) {
+            if proposals.contains_key(&proposed_pos) {
+              proposals.remove(&proposed_pos);
+              clash.insert(proposed_pos);
+            } else {
+              proposals.insert(proposed_pos, *pos);
+            }
+          }
+        }
+      }
+    );
+
+    proposals.iter().for_each(
+      |(to, from)| {
+        let elf = elves.remove(&from).unwrap();
+        elves.insert(*to, elf);
+      }
+    );
+
+    round += 1;
+    proposed = true;
+  }  
+
+  trace_board(&elves);
+
+  let (min_x,max_x,min_y,max_y) = dims(&elves);
+
+  return ((min_x.abs_diff(max_x+1)*min_y.abs_diff(max_y+1)-elves.len() as u64) as Int, round);
+}
+
+pub fn one(elves: &HashMap<Pos, Elf>, _input: &Input) -> String {
+  let (empty_tiles, _) = scatter(elves.clone(), Some(10));
+  return empty_tiles.to_string();
+}
+
+pub fn two(elves: &HashMap<Pos, Elf>, _input: &Input) -> String {
+  let (_, round) = scatter(elves.clone(), None);
+  return round.to_string();
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+  type Parsed = HashMap<Pos, Elf>;
+
+  fn prepare(lines: &[String]) -> HashMap<Pos, Elf> { prepare(lines) }
+  fn one(parsed: &HashMap<Pos, Elf>, input: &Input) -> String { one(parsed, input) }
+  fn two(parsed: &HashMap<Pos, Elf>, input: &Input) -> String { two(parsed, input) }
+}
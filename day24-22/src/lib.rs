@@ -0,0 +1,141 @@
+use common::{Input, Solution};
+use log::trace;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+
+pub type Pos = (usize,usize);
+
+fn gcd(a: usize, b: usize) -> usize { if b == 0 { a } else { gcd(b, a % b) } }
+
+/// The valley inside its walls, with the blizzards where they are at minute 0
+pub struct Valley { pub width: usize, pub height: usize, pub blizzards: Vec<Vec<char>>, pub start: Pos, pub goal: Pos }
+
+impl Valley {
+  /// After this many minutes, every blizzard is back where it started
+  pub fn period(&self) -> usize { self.width * self.height / gcd(self.width, self.height) }
+
+  /// Whether a blizzard is at the position at the given minute.
+  /// Instead of moving the blizzards, look where a blizzard would have to start from to arrive there.
+  pub fn blizzard(&self, (x,y): Pos, minute: usize) -> bool {
+    if y == 0 || y > self.height { return false; }
+    let (x,y) = (x-1, y-1);
+    let (dx, dy) = (minute % self.width, minute % self.height);
+    self.blizzards[y][(x + self.width - dx) % self.width] == '>' ||
+    self.blizzards[y][(x + dx) % self.width]              == '<' ||
+    self.blizzards[(y + self.height - dy) % self.height][x] == 'v' ||
+    self.blizzards[(y + dy) % self.height][x]               == '^'
+  }
+
+  /// The positions the expedition could be at next, including staying where it is
+  pub fn moves(&self, (x,y): Pos) -> Vec<Pos> {
+    let mut moves = vec![(x,y)];
+    let inside = 0 < y && y <= self.height;
+    if y > 1                     { moves.push((x,y-1)); }
+    if y < self.height           { moves.push((x,y+1)); }
+    if inside && x > 1           { moves.push((x-1,y)); }
+    if inside && x < self.width  { moves.push((x+1,y)); }
+    [self.start, self.goal].into_iter().for_each(
+      |end| if end.0 == x && end.1.abs_diff(y) == 1 { moves.push(end) }
+    );
+    moves
+  }
+
+  /// The fastest way from one position to another, starting at the given minute.
+  /// The path holds the position of the expedition at every minute, from the start to the arrival.
+  pub fn cross(&self, from: Pos, to: Pos, start: usize) -> Vec<Pos> {
+    let period = self.period();
+    let mut seen: HashSet<(Pos,usize)> = HashSet::from([(from, start % period)]);
+    let mut parents: Vec<HashMap<Pos,Pos>> = vec![];
+    let mut current = vec![from];
+    let mut minute  = start;
+
+    while !current.contains(&to) {
+      assert!(!current.is_empty(), "The expedition cannot reach {to:?}");
+      minute += 1;
+      let mut next = vec![];
+      let mut came_from = HashMap::new();
+      current.iter().for_each(
+        |pos| {
+          self.moves(*pos).into_iter().for_each(
+            |candidate| {
+              if !self.blizzard(candidate, minute) && seen.insert((candidate, minute % period)) {
+                came_from.insert(candidate, *pos);
+                next.push(candidate);
+              }
+            }
+          );
+        }
+      );
+      parents.push(came_from);
+      current = next;
+    }
+
+    let mut path = vec![to];
+    parents.iter().rev().for_each(|came_from| path.push(came_from[path.last().unwrap()]));
+    path.reverse();
+    return path;
+  }
+
+  /// Cross the valley from one waypoint to the next, and return the positions at every minute
+  pub fn trip(&self, waypoints: &[Pos]) -> Vec<Pos> {
+    waypoints.windows(2).fold(
+      vec![waypoints[0]],
+      |mut path, leg| {
+        let crossing = self.cross(leg[0], leg[1], path.len()-1);
+        trace!("Crossed from {:?} to {:?} in {} minutes", leg[0], leg[1], crossing.len()-1);
+        path.extend(crossing.into_iter().skip(1));
+        path
+      }
+    )
+  }
+}
+
+pub fn prepare(lines: &[String]) -> Valley {
+  let opening = |line: &String| line.find('.').expect("The valley has no opening");
+  let blizzards: Vec<Vec<char>> = lines[1..lines.len()-1].iter().map(
+    |line| line.chars().skip(1).take(line.len()-2).collect()
+  ).collect();
+
+  Valley {
+    width : blizzards[0].len(),
+    height: blizzards.len(),
+    start : (opening(&lines[0]), 0),
+    goal  : (opening(&lines[lines.len()-1]), lines.len()-1),
+    blizzards
+  }
+}
+
+/// Write the path as `minute,x,y` lines to the file given by `--export`, e.g. to animate it
+pub fn export(input: &Input, path: &[Pos]) {
+  if let Some(file) = input.option::<String>("export") {
+    let csv = path.iter().enumerate().fold(
+      "minute,x,y\n".to_string(),
+      |acc, (minute, (x,y))| format!("{acc}{minute},{x},{y}\n")
+    );
+    fs::write(&file, csv).unwrap_or_else(|err| panic!("Failed to export the path to {file}: {err}"));
+  }
+}
+
+pub fn one(valley: &Valley, input: &Input) -> String {
+  let path   = valley.trip(&[valley.start, valley.goal]);
+  export(input, &path);
+
+  return (path.len()-1).to_string();
+}
+
+pub fn two(valley: &Valley, input: &Input) -> String {
+  let path   = valley.trip(&[valley.start, valley.goal, valley.start, valley.goal]);
+  export(input, &path);
+
+  return (path.len()-1).to_string();
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+  type Parsed = Valley;
+
+  fn prepare(lines: &[String]) -> Valley { prepare(lines) }
+  fn one(parsed: &Valley, input: &Input) -> String { one(parsed, input) }
+  fn two(parsed: &Valley, input: &Input) -> String { two(parsed, input) }
+}
@@ -0,0 +1,181 @@
+use common::{Input, Solution};
+use log::trace;
+use std::fmt::Display;
+use std::str::FromStr;
+use itertools::EitherOrBoth::{Left, Right, Both};
+use itertools::Itertools;
+
+pub type Int = i64;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Digit { MTwo = -2, MOne = -1, Zero = 0, One = 1, Two = 2 }
+
+impl Digit {
+  pub fn new(d: i8) -> Digit {
+    match d {
+     -2 => Digit::MTwo,
+     -1 => Digit::MOne,
+      0 => Digit::Zero,
+      1 => Digit::One,
+      2 => Digit::Two,
+      _ => { panic!("Cannot create Digit from number: {d}"); }
+    }
+  }
+
+  pub fn add(&self, other: &Digit) -> (Digit, Digit) {
+    match (*self as i8) + (*other as i8) {
+      sum if sum ==  4 => (Digit::MOne, Digit::One ),
+      sum if sum ==  3 => (Digit::MTwo, Digit::One ),
+      sum if sum == -3 => (Digit::Two , Digit::MOne),
+      sum if sum == -4 => (Digit::One , Digit::MOne),
+      sum              => (Digit::new(sum), Digit::Zero)
+    }
+  }
+
+  pub fn add2(&self, o1: &Digit, o2: &Digit) -> (Digit, Digit) {
+    match (*self as i8) + (*o1 as i8) + (*o2 as i8) {
+      sum if sum ==  6 => (Digit::One , Digit::One ),
+      sum if sum ==  5 => (Digit::Zero, Digit::One ),
+      sum if sum ==  4 => (Digit::MOne, Digit::One ),
+      sum if sum ==  3 => (Digit::MTwo, Digit::One ),
+      sum if sum == -3 => (Digit::Two , Digit::MOne),
+      sum if sum == -4 => (Digit::One , Digit::MOne),
+      sum if sum == -5 => (Digit::Zero, Digit::MOne),
+      sum if sum == -6 => (Digit::MOne, Digit::MOne),
+      sum              => (Digit::new(sum), Digit::Zero)
+    }
+  }
+}
+
+impl Display for Digit {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", 
+      match self {
+        Digit::MTwo => '=',
+        Digit::MOne => '-',
+        Digit::Zero => '0',
+        Digit::One  => '1',
+        Digit::Two  => '2'
+      }
+    )    
+  } 
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct SNAFU { pub digits: Vec<Digit> }
+
+impl SNAFU {
+  pub fn zero() -> SNAFU {
+    SNAFU { digits: vec![Digit::new(0)] }
+  }
+
+  pub fn from_int(mut value: Int) -> SNAFU {
+    let mut digits = vec![];
+    while value != 0 {
+      let digit = (value%5 + 7)%5 - 2;
+      digits.push(Digit::new(digit as i8));
+      value = (value - digit)/5;
+    }
+    if digits.is_empty() { return SNAFU::zero(); }
+    digits.reverse();
+    SNAFU { digits }
+  }
+
+  pub fn add(&self, other: &SNAFU) -> SNAFU {
+    let (mut digits, carry) = self.digits.iter().rev().zip_longest(other.digits.iter().rev()).fold(
+      (vec![], Digit::Zero),
+      |(mut digits, carry), either_digit| {
+        let (digit, c) = match either_digit {
+          Left(l)   => l.add(&carry),
+          Right(r)  => r.add(&carry),
+          Both(l,r) => l.add2(&r,&carry),
+        };
+        digits.push(digit);
+        (digits, c)
+      }
+    );
+    if carry == Digit::One || carry == Digit::Two {
+      digits.push(carry);
+    }
+    digits.reverse();
+    SNAFU { digits }
+  }
+
+
+  pub fn to_int(&self) -> Int {
+    self.digits.iter().rev().enumerate().fold(
+      0, 
+      |acc, (i,d)| {
+        acc + (*d as Int)*num::pow(5,i)
+      }
+    )
+  }
+
+  pub fn sum(snafus: &Vec<SNAFU>) -> SNAFU {
+    snafus.iter().fold(
+      SNAFU::zero(), 
+      |acc, snafu| {
+        acc.add(snafu)
+      }
+    )
+  }
+}
+
+impl Display for SNAFU {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", 
+      self.digits.iter().fold(
+        String::new(),
+        |acc, digit| {
+          format!("{acc}{digit}")
+        }
+      )
+    )
+  }
+}
+
+#[derive(Debug)]
+pub struct ParseSNAFUError;
+
+impl FromStr for SNAFU {
+  type Err = ParseSNAFUError;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let digit_results: Vec<Result<Digit, ParseSNAFUError>> = s.chars().map(
+      |c| {
+        match c {
+          '=' => Ok(Digit::MTwo),
+          '-' => Ok(Digit::MOne),
+          '0' => Ok(Digit::Zero),
+          '1' => Ok(Digit::One),
+          '2' => Ok(Digit::Two),
+           _  => Err(ParseSNAFUError)
+        }
+      }).collect();
+    return if digit_results.iter().all(|d_res| d_res.is_ok()) { Ok(SNAFU { digits: digit_results.into_iter().map(|d_res| d_res.unwrap()).collect()}) } else { Err(ParseSNAFUError) };
+  }
+}
+
+pub fn prepare(lines: &[String]) -> Vec<SNAFU> {
+  return lines.iter().map(|line| line.parse::<SNAFU>().unwrap()).collect();
+}
+
+pub fn one(snafu_numbers: &Vec<SNAFU>, _input: &Input) -> String {
+  let snafu_number = SNAFU::sum(snafu_numbers);
+  trace!("Result: {}", snafu_number.to_int());
+  return snafu_number.to_string();
+}
+
+pub fn two(_snafu_numbers: &Vec<SNAFU>, _input: &Input) -> String {
+  return "Blender, GO!".to_string();
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+  type Parsed = Vec<SNAFU>;
+
+  fn prepare(lines: &[String]) -> Vec<SNAFU> { prepare(lines) }
+  fn one(parsed: &Vec<SNAFU>, input: &Input) -> String { one(parsed, input) }
+  fn two(parsed: &Vec<SNAFU>, input: &Input) -> String { two(parsed, input) }
+}
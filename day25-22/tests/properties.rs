@@ -0,0 +1,32 @@
+use day25_22::SNAFU;
+use proptest::prelude::*;
+
+/// Positive SNAFU numbers as they appear in the input, e.g. `1=-0-2`
+fn snafu_string() -> impl Strategy<Value = String> {
+  "[12][=\\-012]{0,19}"
+}
+
+proptest! {
+  #[test]
+  fn display_from_str_round_trip(s in snafu_string()) {
+    prop_assert_eq!(s.parse::<SNAFU>().unwrap().to_string(), s);
+  }
+
+  #[test]
+  fn from_int_to_int_round_trip(n in 0..1_000_000_000_000_000i64) {
+    prop_assert_eq!(SNAFU::from_int(n).to_int(), n);
+  }
+
+  #[test]
+  fn to_int_from_int_round_trip(s in snafu_string()) {
+    let snafu = s.parse::<SNAFU>().unwrap();
+    prop_assert_eq!(SNAFU::from_int(snafu.to_int()), snafu);
+  }
+
+  #[test]
+  fn add_matches_integer_addition(a in snafu_string(), b in snafu_string()) {
+    let (a, b) = (a.parse::<SNAFU>().unwrap(), b.parse::<SNAFU>().unwrap());
+    prop_assert_eq!(a.add(&b).to_int(), a.to_int() + b.to_int());
+    prop_assert_eq!(a.add(&b), SNAFU::from_int(a.to_int() + b.to_int()));
+  }
+}